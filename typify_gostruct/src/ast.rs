@@ -2,47 +2,140 @@ use std::collections::HashMap;
 use std::fmt::{self, Display};
 
 use crate::scanner::Position;
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct StructDeclaration {
     pub name: String,
+    pub doc: Option<String>,
+    pub type_parameters: Vec<TypeParameter>,
     pub body: Vec<Field>,
 }
 
+/// A type parameter of a generic declaration, `[T ~int | ~float64]` has the constraint `[Integer { .. }, Float]`.
+/// Constraints that aren't a union of predeclared types (`any`, `comparable`, interfaces) are left empty.
+#[derive(Debug, Clone)]
+pub struct TypeParameter {
+    pub name: String,
+    pub constraint: Vec<DataType>,
+}
+
+/// A non-struct type declaration, either defined (`type Status string`) or aliased (`type ID = int64`).
+/// `variants` holds the constants declared with this type, which turn it into an enum.
+#[derive(Debug)]
+pub struct TypeDeclaration {
+    pub name: String,
+    pub doc: Option<String>,
+    pub type_parameters: Vec<TypeParameter>,
+    pub definition: FieldType,
+    pub variants: Vec<ConstDeclaration>,
+}
+
+/// A single constant, `value` is `None` when its expression is too involved to evaluate.
+#[derive(Debug, Clone)]
+pub struct ConstDeclaration {
+    pub name: String,
+    pub type_name: Option<String>,
+    pub value: Option<ConstValue>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstValue {
+    String(String),
+    Integer(i64),
+}
+
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Debug)]
 pub enum AST {
     Declaration(Box<StructDeclaration>),
+    Definition(Box<TypeDeclaration>),
+    Alias(Box<TypeDeclaration>),
+    Constant(Box<ConstDeclaration>),
     Field(Field),
 }
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FieldName(pub String);
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 
 pub enum FieldType {
-    One(DataType),
-    List(DataType),
+    Named(DataType),
+    Qualified(String, String),
+    Slice(Box<FieldType>),
+    Array(String, Box<FieldType>),
+    Pointer(Box<FieldType>),
+    Map(Box<FieldType>, Box<FieldType>),
+    /// A generic type instantiated with type arguments, e.g. `Page[User]`.
+    Instantiated(Box<FieldType>, Vec<FieldType>),
+    /// An anonymous `struct { ... }` type, its declaration carries no name.
+    Struct(Box<StructDeclaration>),
 }
-#[derive(Debug)]
-
-pub struct Tag {
-    name: String,
-    value: String,
-}
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Field {
     Blank,
     Plain(FieldName, FieldType),
     WithTags(FieldName, FieldType, HashMap<TagKey, TagValue>),
+    /// A field preceded by a doc comment.
+    Documented(String, Box<Field>),
 }
 
-#[derive(Debug, Hash, Eq, PartialEq)]
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
 pub struct TagKey(pub String);
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct TagValue(pub String);
 
+/// The `json` tag of a field, read the way encoding/json reads it.
+#[derive(Debug, Default, PartialEq)]
+pub struct JsonTag {
+    /// `None` when the tag doesn't rename the field.
+    pub name: Option<String>,
+    /// `json:"-"`, the field is never marshaled.
+    pub skip: bool,
+    pub omit_empty: bool,
+    /// `json:",string"`, scalars are marshaled inside a JSON string.
+    pub as_string: bool,
+}
+
+impl JsonTag {
+    pub fn from_tags(tags: &HashMap<TagKey, TagValue>) -> Self {
+        let value = match tags.get(&TagKey("json".to_string())) {
+            Some(TagValue(value)) => value,
+            None => return Self::default(),
+        };
+        if value == "-" {
+            return Self {
+                skip: true,
+                ..Self::default()
+            };
+        }
+        let mut parts = value.split(',');
+        let name = parts.next().filter(|name| !name.is_empty());
+        let options: Vec<&str> = parts.collect();
+        Self {
+            name: name.map(str::to_string),
+            skip: false,
+            omit_empty: options.contains(&"omitempty"),
+            as_string: options.contains(&"string"),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum DataType {
-    Number,
+    /// The integers that fit in a JavaScript number, `int`, `uint32` or `rune` for instance.
+    Integer {
+        bits: u8,
+        signed: bool,
+    },
+    /// `float32` and `float64`.
+    Float,
+    /// `int64` and `uint64`, which don't fit in a JavaScript number.
+    LargeNumber {
+        signed: bool,
+    },
+    /// `complex64` and `complex128`, which encoding/json refuses to marshal.
+    Complex,
+    /// `byte` and `uint8`, which are numbers on their own but make up strings as a slice.
+    Byte,
     String,
     Boolean,
     Custom(String),
@@ -56,7 +149,10 @@ pub enum RequiredElements {
     LeftBrace,
     Identifier,
     Colon,
+    LeftBracket,
     RightBracket,
+    LeftParen,
+    Chan,
 }
 
 impl Display for RequiredElements {
@@ -67,7 +163,10 @@ impl Display for RequiredElements {
             RequiredElements::LeftBrace => write!(f, "LeftBrace"),
             RequiredElements::Identifier => write!(f, "Identifier"),
             RequiredElements::Colon => write!(f, "Colon"),
+            RequiredElements::LeftBracket => write!(f, "LeftBracket"),
             RequiredElements::RightBracket => write!(f, "RightBracket"),
+            RequiredElements::LeftParen => write!(f, "LeftParen"),
+            RequiredElements::Chan => write!(f, "Chan"),
         }
     }
 }
@@ -79,6 +178,9 @@ pub enum ParseError {
     UnexpectedEndOfFile,
     Missing(RequiredElements, String, Position),
     UnexpectedEndOfStruct,
+    UnexpectedEndOfGroup,
+    /// A function or channel type, which encoding/json can't marshal.
+    UnsupportedType(String),
 }
 
 impl fmt::Display for ParseError {
@@ -99,6 +201,12 @@ impl fmt::Display for ParseError {
             &ParseError::UnexpectedEndOfStruct => {
                 write!(f, "Expected `}}` before the end of the struct")
             }
+            &ParseError::UnexpectedEndOfGroup => {
+                write!(f, "Expected `)` before the end of the type group")
+            }
+            ParseError::UnsupportedType(element) => {
+                write!(f, "`{}` types can't be marshalled to JSON", element)
+            }
         }
     }
 }
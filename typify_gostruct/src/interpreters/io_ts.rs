@@ -0,0 +1,137 @@
+use crate::ast::{ConstValue, DataType, AST};
+
+use super::registry::JsonKind;
+use super::{Interpreter, InterpreterError, LargeIntegerStyle, Options, RuntimeValidators, Scope};
+
+pub struct IoTsInterpreter {
+    options: Options,
+}
+
+impl Interpreter for IoTsInterpreter {
+    fn interpret(&self, ast: Vec<AST>) -> Result<String, InterpreterError> {
+        self.interpret_validators(ast)
+    }
+}
+
+impl IoTsInterpreter {
+    pub fn new(options: Options) -> Self {
+        Self { options }
+    }
+}
+
+impl RuntimeValidators for IoTsInterpreter {
+    fn options(&self) -> &Options {
+        &self.options
+    }
+
+    fn library(&self) -> (&'static str, &'static str) {
+        ("* as t", "io-ts")
+    }
+
+    /// Codecs share the name of their type.
+    fn validator_name(&self, name: &str) -> String {
+        name.to_string()
+    }
+
+    fn validator_type(&self, type_expression: &str) -> String {
+        format!("t.Type<{}>", type_expression)
+    }
+
+    fn inferred_type(&self, constant: &str) -> String {
+        format!("t.TypeOf<typeof {}>", constant)
+    }
+
+    fn type_parameter_bound(&self) -> &'static str {
+        "t.Mixed"
+    }
+
+    fn lazy(&self, name: &str, codec: String) -> String {
+        let printer = self.printer();
+        format!(
+            "t.recursion({}, () =>\n{},\n)",
+            printer.literal(&ConstValue::String(name.to_string())),
+            printer.indent(&codec)
+        )
+    }
+
+    fn data_type(&self, data_type: DataType, scope: &Scope) -> Option<String> {
+        let codec = match data_type {
+            DataType::Integer { .. } | DataType::Float | DataType::Byte => "t.number".to_string(),
+            DataType::LargeNumber { .. } => match self.options.large_integers {
+                LargeIntegerStyle::Number => "t.number".to_string(),
+                LargeIntegerStyle::String => "t.string".to_string(),
+                LargeIntegerStyle::BigInt => "t.bigint".to_string(),
+            },
+            DataType::Complex => "t.never".to_string(),
+            DataType::String => "t.string".to_string(),
+            DataType::Boolean => "t.boolean".to_string(),
+            DataType::Custom(name) => self.reference(name, scope),
+            DataType::Embedded => return None,
+        };
+        Some(codec)
+    }
+
+    fn known_type(&self, json: JsonKind, _format: Option<&str>) -> String {
+        let codec = match json {
+            JsonKind::Any => "t.unknown",
+            JsonKind::String => "t.string",
+            JsonKind::Number | JsonKind::Integer => "t.number",
+            JsonKind::Boolean => "t.boolean",
+        };
+        codec.to_string()
+    }
+
+    /// There's no codec to decode mapped types, the cast keeps them in `t.TypeOf`.
+    fn mapped_type(&self, type_expression: &str) -> String {
+        format!("t.unknown as t.Mixed as t.Type<{}>", type_expression)
+    }
+
+    fn unknown(&self) -> String {
+        "t.unknown".to_string()
+    }
+
+    fn array(&self, element: String, _length: Option<usize>) -> String {
+        format!("t.array({})", element)
+    }
+
+    fn nullable(&self, codec: String) -> String {
+        format!("t.union([{}, t.null])", codec)
+    }
+
+    fn record(&self, value: String) -> String {
+        format!("t.record(t.string, {})", value)
+    }
+
+    /// `t.type` for the required properties and `t.partial` for the `omitempty` ones,
+    /// intersected when a struct has both. Neither strips unknown fields.
+    fn object(&self, properties: Vec<(String, bool)>, _extended: bool) -> String {
+        let (optional, required): (Vec<_>, Vec<_>) =
+            properties.into_iter().partition(|(_, optional)| *optional);
+        let optional: Vec<String> = optional.into_iter().map(|(property, _)| property).collect();
+        let required: Vec<String> = required.into_iter().map(|(property, _)| property).collect();
+        let printer = self.printer();
+        if optional.is_empty() {
+            return format!("t.type({})", printer.object_literal(required));
+        }
+        let partial = format!("t.partial({})", printer.object_literal(optional));
+        if required.is_empty() {
+            return partial;
+        }
+        let required = format!("t.type({})", printer.object_literal(required));
+        format!(
+            "t.intersection({})",
+            printer.array_literal(vec![required, partial])
+        )
+    }
+
+    fn enumeration(&self, literals: Vec<String>, _strings: bool) -> String {
+        let literals: Vec<String> = literals
+            .into_iter()
+            .map(|literal| format!("t.literal({})", literal))
+            .collect();
+        match &literals[..] {
+            [literal] => literal.clone(),
+            literals => format!("t.union([{}])", literals.join(", ")),
+        }
+    }
+}
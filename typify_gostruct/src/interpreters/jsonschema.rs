@@ -0,0 +1,411 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::ast::{
+    ConstValue, DataType, Field, FieldType, JsonTag, StructDeclaration, TagKey, TagValue,
+    TypeDeclaration, AST,
+};
+
+use super::printer::Printer;
+use super::registry::{self, JsonKind, KnownType};
+use super::{Interpreter, InterpreterError, LargeIntegerStyle, Options, Separator};
+
+static DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+pub struct JsonSchemaInterpreter {
+    options: Options,
+}
+
+/// A JSON value, schemas are built out of them before being printed.
+enum Json {
+    String(String),
+    /// Already valid JSON, e.g. a number or the literal of a constant.
+    Raw(String),
+    Bool(bool),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+/// A property of an object schema.
+struct Property {
+    name: String,
+    schema: Json,
+    required: bool,
+}
+
+impl Interpreter for JsonSchemaInterpreter {
+    fn interpret(&self, ast: Vec<AST>) -> Result<String, InterpreterError> {
+        let declared: HashSet<String> = ast
+            .iter()
+            .filter_map(|item| match item {
+                AST::Declaration(declaration) => Some(declaration.name.clone()),
+                AST::Definition(declaration) | AST::Alias(declaration) => {
+                    Some(declaration.name.clone())
+                }
+                _ => None,
+            })
+            .collect();
+        let mut definitions = Vec::new();
+        for item in ast {
+            let definition = match item {
+                AST::Declaration(declaration) => {
+                    let name = declaration.name.clone();
+                    (name, self.interpret_struct(*declaration, &declared))
+                }
+                AST::Definition(declaration) | AST::Alias(declaration) => {
+                    let name = declaration.name.clone();
+                    match self.interpret_type_declaration(*declaration, &declared) {
+                        Some(schema) => (name, schema),
+                        None => continue,
+                    }
+                }
+                AST::Constant(_) => continue,
+                _ => return Err(InterpreterError::ExpectedStructFoundField),
+            };
+            definitions.push(definition)
+        }
+        let schema = object(vec![
+            ("$schema", Json::String(DRAFT.to_string())),
+            ("$defs", Json::Object(definitions)),
+        ]);
+        Ok(self.printer().document(vec![self.render(&schema, 0)]))
+    }
+}
+
+impl JsonSchemaInterpreter {
+    pub fn new(options: Options) -> Self {
+        Self { options }
+    }
+    fn printer(&self) -> Printer<'_> {
+        Printer::new(&self.options.formatting, Separator::Comma)
+    }
+    fn get_field_type(&self, data_type: DataType, declared: &HashSet<String>) -> Option<Json> {
+        let schema = match data_type {
+            DataType::Integer { .. } | DataType::Byte => type_schema("integer"),
+            DataType::Float => type_schema("number"),
+            DataType::LargeNumber { .. } => match self.options.large_integers {
+                LargeIntegerStyle::String => type_schema("string"),
+                LargeIntegerStyle::Number | LargeIntegerStyle::BigInt => type_schema("integer"),
+            },
+            // encoding/json refuses to marshal complex numbers, no value is valid.
+            DataType::Complex => Json::Bool(false),
+            DataType::String => type_schema("string"),
+            DataType::Boolean => type_schema("boolean"),
+            DataType::Custom(name) if declared.contains(&name) => {
+                object(vec![("$ref", Json::String(format!("#/$defs/{}", name)))])
+            }
+            // Type parameters and types that aren't declared in the input can be anything.
+            DataType::Custom(_) => object(vec![]),
+            DataType::Embedded => return None,
+        };
+        Some(schema)
+    }
+
+    fn interpret_struct(&self, declaration: StructDeclaration, declared: &HashSet<String>) -> Json {
+        let mut schema = Vec::new();
+        if let Some(doc) = declaration.doc {
+            schema.push(("description".to_string(), Json::String(doc)))
+        }
+        schema.extend(self.interpret_struct_body(declaration.body, declared));
+        Json::Object(schema)
+    }
+
+    fn interpret_type_declaration(
+        &self,
+        declaration: TypeDeclaration,
+        declared: &HashSet<String>,
+    ) -> Option<Json> {
+        let mut schema = match self.convert_field_type(declaration.definition, declared)? {
+            Json::Object(members) => members,
+            schema => vec![("allOf".to_string(), Json::Array(vec![schema]))],
+        };
+        let literals: Option<Vec<Json>> = declaration
+            .variants
+            .iter()
+            .map(|variant| variant.value.as_ref().map(literal))
+            .collect();
+        match literals {
+            Some(literals) if !literals.is_empty() => {
+                schema.push(("enum".to_string(), Json::Array(literals)))
+            }
+            _ => {}
+        }
+        if let Some(doc) = declaration.doc {
+            schema.insert(0, ("description".to_string(), Json::String(doc)))
+        }
+        Some(Json::Object(schema))
+    }
+
+    fn interpret_struct_body(
+        &self,
+        body: Vec<Field>,
+        declared: &HashSet<String>,
+    ) -> Vec<(String, Json)> {
+        let mut properties = Vec::new();
+        let mut required = Vec::new();
+        for field in body {
+            if let Some(property) = self.interpret_field(field, None, declared) {
+                if property.required {
+                    required.push(Json::String(property.name.clone()))
+                }
+                properties.push((property.name, property.schema))
+            }
+        }
+        let mut schema = vec![
+            ("type".to_string(), Json::String("object".to_string())),
+            ("properties".to_string(), Json::Object(properties)),
+        ];
+        if !required.is_empty() {
+            schema.push(("required".to_string(), Json::Array(required)))
+        }
+        schema
+    }
+
+    fn interpret_field(
+        &self,
+        field: Field,
+        doc: Option<String>,
+        declared: &HashSet<String>,
+    ) -> Option<Property> {
+        match field {
+            Field::Blank => None,
+            Field::Documented(doc, field) => self.interpret_field(*field, Some(doc), declared),
+            Field::Plain(field_name, field_type) => {
+                let tags = HashMap::new();
+                self.interpret_property(field_name.0, field_type, &tags, doc, declared)
+            }
+            Field::WithTags(field_name, field_type, tags) => {
+                self.interpret_property(field_name.0, field_type, &tags, doc, declared)
+            }
+        }
+    }
+
+    /// Properties are required unless they are `omitempty` (or optional pointers), a
+    /// `binding:"required"` tag makes them required regardless.
+    fn interpret_property(
+        &self,
+        field_name: String,
+        field_type: FieldType,
+        tags: &HashMap<TagKey, TagValue>,
+        doc: Option<String>,
+        declared: &HashSet<String>,
+    ) -> Option<Property> {
+        let json_tag = JsonTag::from_tags(tags);
+        if json_tag.skip {
+            return None;
+        }
+        let field_type = if json_tag.as_string {
+            super::quoted_type(field_type)
+        } else {
+            field_type
+        };
+        let is_pointer = matches!(field_type, FieldType::Pointer(..));
+        let optional = json_tag.omit_empty || (self.options.optional_pointers && is_pointer);
+        let binding_required = tags
+            .get(&TagKey("binding".to_string()))
+            .is_some_and(|TagValue(value)| value.split(',').any(|rule| rule == "required"));
+        // Embedded types that aren't declared in the input have no schema to inline.
+        let mut schema = self.convert_field_type(field_type, declared)?;
+        if let (Some(doc), Json::Object(members)) = (doc, &mut schema) {
+            members.insert(0, ("description".to_string(), Json::String(doc)))
+        }
+        Some(Property {
+            name: json_tag.name.unwrap_or(field_name),
+            schema,
+            required: binding_required || !optional,
+        })
+    }
+
+    fn convert_field_type(
+        &self,
+        field_type: FieldType,
+        declared: &HashSet<String>,
+    ) -> Option<Json> {
+        // Mapped types without a schema of their own are left unchecked.
+        if let Some(mapping) = super::mapped_type(&self.options, &field_type) {
+            return Some(match &mapping.json_schema {
+                Some(json_schema) => Json::Raw(json_schema.clone()),
+                None => object(vec![]),
+            });
+        }
+        if let Some(known_type) = registry::known_type(&field_type) {
+            return Some(self.convert_known_type(known_type));
+        }
+        match field_type {
+            FieldType::Named(data_type) => self.get_field_type(data_type, declared),
+            FieldType::Qualified(..) => Some(object(vec![])),
+            FieldType::Slice(element_type) => self.convert_list_type(None, *element_type, declared),
+            FieldType::Array(length, element_type) => {
+                self.convert_list_type(length.parse().ok(), *element_type, declared)
+            }
+            FieldType::Pointer(pointee) => self.convert_pointer_type(*pointee, declared),
+            // Object keys are always strings, whatever the type of the map keys.
+            FieldType::Map(_, value_type) => Some(object(vec![
+                ("type", Json::String("object".to_string())),
+                (
+                    "additionalProperties",
+                    self.convert_field_type(*value_type, declared)?,
+                ),
+            ])),
+            // Schemas can't be generic, the type arguments are left unchecked.
+            FieldType::Instantiated(generic_type, _) => {
+                self.convert_field_type(*generic_type, declared)
+            }
+            FieldType::Struct(declaration) => Some(Json::Object(
+                self.interpret_struct_body(declaration.body, declared),
+            )),
+        }
+    }
+
+    fn convert_known_type(&self, known_type: &KnownType) -> Json {
+        let mut schema = match known_type.json {
+            JsonKind::Any => return object(vec![]),
+            JsonKind::String => vec![("type", Json::String("string".to_string()))],
+            JsonKind::Number => vec![("type", Json::String("number".to_string()))],
+            JsonKind::Integer => vec![("type", Json::String("integer".to_string()))],
+            JsonKind::Boolean => vec![("type", Json::String("boolean".to_string()))],
+        };
+        if let Some(format) = known_type.format {
+            schema.push(("format", Json::String(format.to_string())))
+        }
+        if known_type.nullable {
+            nullable(object(schema))
+        } else {
+            object(schema)
+        }
+    }
+
+    fn convert_pointer_type(&self, pointee: FieldType, declared: &HashSet<String>) -> Option<Json> {
+        if super::is_nullable(&pointee) {
+            return self.convert_field_type(pointee, declared);
+        }
+        self.convert_field_type(pointee, declared).map(nullable)
+    }
+
+    fn convert_list_type(
+        &self,
+        length: Option<usize>,
+        element_type: FieldType,
+        declared: &HashSet<String>,
+    ) -> Option<Json> {
+        let mut schema = vec![
+            ("type", Json::String("array".to_string())),
+            ("items", self.convert_field_type(element_type, declared)?),
+        ];
+        if let Some(length) = length {
+            schema.push(("minItems", Json::Raw(length.to_string())));
+            schema.push(("maxItems", Json::Raw(length.to_string())))
+        }
+        Some(object(schema))
+    }
+
+    /// Renders `value` on a line indented `depth` levels.
+    fn render(&self, value: &Json, depth: usize) -> String {
+        match value {
+            Json::String(value) => json_string(value),
+            Json::Raw(value) => value.clone(),
+            Json::Bool(value) => value.to_string(),
+            Json::Array(items) => self.printer().json_block(
+                ("[", "]"),
+                items
+                    .iter()
+                    .map(|item| self.render(item, depth + 1))
+                    .collect(),
+            ),
+            Json::Object(members) => self.printer().json_block(
+                ("{", "}"),
+                members
+                    .iter()
+                    .map(|(key, value)| self.render_member(key, value, depth + 1))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Arrays of scalars stay on the member's line when they fit, as Prettier lays them out.
+    fn render_member(&self, key: &str, value: &Json, depth: usize) -> String {
+        let key = json_string(key);
+        if let Json::Array(items) = value {
+            if items.iter().all(is_scalar) {
+                let items: Vec<String> = items.iter().map(|item| self.render(item, 0)).collect();
+                let member = format!("{}: [{}]", key, items.join(", "));
+                // Leaves room for the separator.
+                if self.printer().fits(depth, &format!("{},", member)) {
+                    return member;
+                }
+            }
+        }
+        format!("{}: {}", key, self.render(value, depth))
+    }
+}
+
+fn object(members: Vec<(&str, Json)>) -> Json {
+    Json::Object(
+        members
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect(),
+    )
+}
+
+fn type_schema(name: &str) -> Json {
+    object(vec![("type", Json::String(name.to_string()))])
+}
+
+/// `{ "type": "string" }` becomes `{ "type": ["string", "null"] }`, other schemas are
+/// combined with the `null` type.
+fn nullable(schema: Json) -> Json {
+    match schema {
+        Json::Object(mut members) => {
+            if members.is_empty() {
+                // An empty schema already accepts `null`.
+                return Json::Object(members);
+            }
+            if let Some((_, schema_type)) = members.iter_mut().find(|(key, _)| key == "type") {
+                if let Json::String(name) = schema_type {
+                    *schema_type = Json::Array(vec![
+                        Json::String(name.clone()),
+                        Json::String("null".to_string()),
+                    ]);
+                    return Json::Object(members);
+                }
+            }
+            object(vec![(
+                "anyOf",
+                Json::Array(vec![Json::Object(members), type_schema("null")]),
+            )])
+        }
+        schema => object(vec![(
+            "anyOf",
+            Json::Array(vec![schema, type_schema("null")]),
+        )]),
+    }
+}
+
+fn literal(value: &ConstValue) -> Json {
+    match value {
+        ConstValue::String(literal) => Json::String(super::unescape_go_string(literal)),
+        ConstValue::Integer(value) => Json::Raw(value.to_string()),
+    }
+}
+
+fn is_scalar(value: &Json) -> bool {
+    matches!(value, Json::String(_) | Json::Raw(_) | Json::Bool(_))
+}
+
+fn json_string(value: &str) -> String {
+    let mut result = "\"".to_string();
+    for character in value.chars() {
+        match character {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\t' => result.push_str("\\t"),
+            character if character.is_control() => {
+                result.push_str(&format!("\\u{:04x}", character as u32))
+            }
+            character => result.push(character),
+        }
+    }
+    result.push('"');
+    result
+}
@@ -1,16 +1,725 @@
-use crate::ast::AST;
+use crate::ast::{self, AST};
+use std::collections::{HashMap, HashSet};
 use std::fmt::{self, Display};
 
-use self::{flow::FlowInterpreter, typescript::TypeScriptInterpreter};
+use self::{
+    flow::FlowInterpreter, io_ts::IoTsInterpreter, jsonschema::JsonSchemaInterpreter,
+    rust::RustInterpreter, typescript::TypeScriptInterpreter, zod::ZodInterpreter,
+};
+
+pub use self::options::{
+    DeclarationStyle, EnumStyle, ExportStyle, FlowOptions, Formatting, Indent, LargeIntegerStyle,
+    ObjectStyle, Options, QuoteStyle, Separator, TypeMapping, TypeScriptOptions,
+};
+use self::printer::Printer;
 
 mod flow;
+mod io_ts;
+mod jsonschema;
+mod options;
+mod printer;
+mod registry;
+mod rust;
 mod typescript;
+mod zod;
 
 pub enum FieldType {
     Normal(String),
     Embedded,
 }
 
+impl FieldType {
+    fn map(self, transform: impl FnOnce(String) -> String) -> Self {
+        match self {
+            FieldType::Normal(field_type) => FieldType::Normal(transform(field_type)),
+            FieldType::Embedded => FieldType::Embedded,
+        }
+    }
+
+    fn and_then(self, transform: impl FnOnce(String) -> FieldType) -> Self {
+        match self {
+            FieldType::Normal(field_type) => transform(field_type),
+            FieldType::Embedded => FieldType::Embedded,
+        }
+    }
+}
+
+/// Renders a doc comment as JSDoc.
+fn doc_comment(doc: &str) -> String {
+    let doc = doc.replace("*/", "*\\/");
+    let lines: Vec<&str> = doc.lines().collect();
+    match lines.as_slice() {
+        [line] => format!("/** {} */", line),
+        lines => {
+            let mut result = "/**\n".to_string();
+            for line in lines {
+                result.push_str(format!(" * {}", line).trim_end());
+                result.push('\n');
+            }
+            result.push_str(" */");
+            result
+        }
+    }
+}
+
+fn declaration_doc(doc: Option<&str>) -> String {
+    match doc {
+        Some(doc) => format!("{}\n", doc_comment(doc)),
+        None => String::new(),
+    }
+}
+
+/// Renders the literals of an enum-like type, `None` when it has no (or unevaluated) constants.
+fn enum_literals(printer: &Printer, variants: &[ast::ConstDeclaration]) -> Option<Vec<String>> {
+    if variants.is_empty() {
+        return None;
+    }
+    variants
+        .iter()
+        .map(|variant| variant.value.as_ref().map(|value| printer.literal(value)))
+        .collect()
+}
+
+/// Decodes the escape sequences of the content of an interpreted Go string literal. Octal and
+/// `\x` escapes are bytes, which may add up to UTF-8 characters.
+fn unescape_go_string(literal: &str) -> String {
+    fn push_character(bytes: &mut Vec<u8>, character: char) {
+        bytes.extend_from_slice(character.encode_utf8(&mut [0; 4]).as_bytes())
+    }
+    fn number(characters: &mut std::str::Chars, digits: usize, radix: u32) -> Option<u32> {
+        let digits: String = characters.take(digits).collect();
+        u32::from_str_radix(&digits, radix).ok()
+    }
+    let mut bytes = Vec::with_capacity(literal.len());
+    let mut characters = literal.chars();
+    while let Some(character) = characters.next() {
+        if character != '\\' {
+            push_character(&mut bytes, character);
+            continue;
+        }
+        match characters.next() {
+            Some('a') => bytes.push(0x07),
+            Some('b') => bytes.push(0x08),
+            Some('f') => bytes.push(0x0c),
+            Some('n') => bytes.push(b'\n'),
+            Some('r') => bytes.push(b'\r'),
+            Some('t') => bytes.push(b'\t'),
+            Some('v') => bytes.push(0x0b),
+            Some('x') => bytes.extend(number(&mut characters, 2, 16).map(|byte| byte as u8)),
+            Some(escape @ ('u' | 'U')) => {
+                let digits = if escape == 'u' { 4 } else { 8 };
+                if let Some(character) =
+                    number(&mut characters, digits, 16).and_then(char::from_u32)
+                {
+                    push_character(&mut bytes, character)
+                }
+            }
+            Some(digit @ '0'..='7') => {
+                let rest = number(&mut characters, 2, 8).unwrap_or_default();
+                bytes.push((digit.to_digit(8).unwrap_or_default() * 64 + rest) as u8)
+            }
+            Some(escaped) => push_character(&mut bytes, escaped),
+            None => {}
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+fn large_integer_type(style: LargeIntegerStyle) -> &'static str {
+    match style {
+        LargeIntegerStyle::Number => "number",
+        LargeIntegerStyle::String => "string",
+        LargeIntegerStyle::BigInt => "bigint",
+    }
+}
+
+/// The user-supplied mapping of a named type, if there is one.
+fn mapped_type<'o>(options: &'o Options, field_type: &ast::FieldType) -> Option<&'o TypeMapping> {
+    match field_type {
+        ast::FieldType::Named(ast::DataType::Custom(name)) => options.type_mappings.get(name),
+        ast::FieldType::Qualified(package, name) => {
+            options.type_mappings.get(&format!("{}.{}", package, name))
+        }
+        _ => None,
+    }
+}
+
+/// Renders the imports of the mapped types used by the declarations, each one once.
+fn mapped_imports(options: &Options, ast: &[AST]) -> String {
+    let mut imports: Vec<&str> = Vec::new();
+    let mut collect = |field_type: &ast::FieldType| {
+        if let Some(import) = mapped_type(options, field_type).and_then(|m| m.import.as_deref()) {
+            if !imports.contains(&import) {
+                imports.push(import)
+            }
+        }
+    };
+    for item in ast {
+        match item {
+            AST::Declaration(declaration) => visit_fields(&declaration.body, &mut collect),
+            AST::Definition(declaration) | AST::Alias(declaration) => {
+                visit_field_type(&declaration.definition, &mut collect)
+            }
+            AST::Field(field) => visit_fields(std::slice::from_ref(field), &mut collect),
+            AST::Constant(_) => {}
+        }
+    }
+    imports.join("\n")
+}
+
+fn visit_fields(fields: &[ast::Field], visit: &mut impl FnMut(&ast::FieldType)) {
+    for field in fields {
+        match field {
+            ast::Field::Plain(_, field_type) | ast::Field::WithTags(_, field_type, _) => {
+                visit_field_type(field_type, visit)
+            }
+            ast::Field::Documented(_, field) => visit_fields(std::slice::from_ref(field), visit),
+            ast::Field::Blank => {}
+        }
+    }
+}
+
+fn visit_field_type(field_type: &ast::FieldType, visit: &mut impl FnMut(&ast::FieldType)) {
+    visit(field_type);
+    match field_type {
+        ast::FieldType::Slice(element_type)
+        | ast::FieldType::Array(_, element_type)
+        | ast::FieldType::Pointer(element_type) => visit_field_type(element_type, visit),
+        ast::FieldType::Map(key_type, value_type) => {
+            visit_field_type(key_type, visit);
+            visit_field_type(value_type, visit)
+        }
+        ast::FieldType::Instantiated(generic_type, type_arguments) => {
+            visit_field_type(generic_type, visit);
+            for type_argument in type_arguments {
+                visit_field_type(type_argument, visit)
+            }
+        }
+        ast::FieldType::Struct(declaration) => visit_fields(&declaration.body, visit),
+        ast::FieldType::Named(_) | ast::FieldType::Qualified(..) => {}
+    }
+}
+
+/// The embedded types left by the resolver, they aren't declared in the input.
+fn embedded_types(body: &[ast::Field]) -> Vec<String> {
+    body.iter()
+        .filter_map(|field| match field {
+            ast::Field::Plain(name, ast::FieldType::Named(ast::DataType::Embedded))
+            | ast::Field::WithTags(name, ast::FieldType::Named(ast::DataType::Embedded), _) => {
+                Some(name.0.clone())
+            }
+            ast::Field::Documented(_, field) => embedded_types(std::slice::from_ref(field)).pop(),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The embedded types that an object type can extend (or spread). Those of other packages
+/// aren't declared in the output, so they're dropped unless they are mapped.
+fn extended_types(options: &Options, body: &[ast::Field]) -> Vec<String> {
+    embedded_types(body)
+        .into_iter()
+        .filter_map(|name| match options.type_mappings.get(&name) {
+            Some(mapping) => Some(mapping.type_expression.clone()),
+            None if name.contains('.') => None,
+            None => Some(name),
+        })
+        .collect()
+}
+
+/// `json:",string"` only applies to scalars (and pointers to them), which then become strings.
+fn quoted_type(field_type: ast::FieldType) -> ast::FieldType {
+    match field_type {
+        ast::FieldType::Named(
+            ast::DataType::Integer { .. }
+            | ast::DataType::Float
+            | ast::DataType::LargeNumber { .. }
+            | ast::DataType::Byte
+            | ast::DataType::Boolean,
+        ) => ast::FieldType::Named(ast::DataType::String),
+        ast::FieldType::Pointer(pointee) if matches!(*pointee, ast::FieldType::Named(_)) => {
+            ast::FieldType::Pointer(Box::new(quoted_type(*pointee)))
+        }
+        field_type => field_type,
+    }
+}
+
+/// Compound types can't take the `T[]` shorthand and have to be wrapped in `Array<T>` instead.
+fn is_compound(field_type: &ast::FieldType) -> bool {
+    match field_type {
+        ast::FieldType::Map(..) | ast::FieldType::Struct(..) => true,
+        field_type => is_nullable(field_type),
+    }
+}
+
+/// Pointers marshal to `null` when they are nil, as do the well-known nullable types.
+fn is_nullable(field_type: &ast::FieldType) -> bool {
+    match field_type {
+        ast::FieldType::Pointer(..) => true,
+        field_type => registry::known_type(field_type).is_some_and(|known| known.nullable),
+    }
+}
+
+/// Marks the property as optional when it's `omitempty`, or its field is a pointer and the
+/// options ask for it.
+fn property_name(
+    options: &Options,
+    printer: &Printer,
+    name: String,
+    field_type: &ast::FieldType,
+    omit_empty: bool,
+) -> String {
+    let is_pointer = matches!(field_type, ast::FieldType::Pointer(..));
+    let key = printer.property_key(name);
+    if omit_empty || (options.optional_pointers && is_pointer) {
+        format!("{}?", key)
+    } else {
+        key
+    }
+}
+
+fn parameter_names(type_parameters: &[ast::TypeParameter]) -> Vec<String> {
+    type_parameters
+        .iter()
+        .map(|parameter| parameter.name.clone())
+        .collect()
+}
+
+/// The declared types a declaration refers to.
+fn references(item: &AST, declared: &HashSet<String>) -> Vec<String> {
+    let mut references = Vec::new();
+    let mut collect = |field_type: &ast::FieldType| {
+        if let ast::FieldType::Named(ast::DataType::Custom(name)) = field_type {
+            if declared.contains(name) && !references.contains(name) {
+                references.push(name.clone())
+            }
+        }
+    };
+    match item {
+        AST::Declaration(declaration) => visit_fields(&declaration.body, &mut collect),
+        AST::Definition(declaration) | AST::Alias(declaration) => {
+            visit_field_type(&declaration.definition, &mut collect)
+        }
+        AST::Constant(_) | AST::Field(_) => {}
+    }
+    references
+}
+
+/// Orders the declarations so that runtime values (schemas, codecs) are declared before they are
+/// used, the input order is kept otherwise. Only recursive declarations can refer to later ones.
+fn declaration_order(names: &[String], dependencies: &HashMap<String, Vec<String>>) -> Vec<String> {
+    fn visit(
+        name: &str,
+        dependencies: &HashMap<String, Vec<String>>,
+        visited: &mut HashSet<String>,
+        order: &mut Vec<String>,
+    ) {
+        if !visited.insert(name.to_string()) {
+            return;
+        }
+        for dependency in dependencies.get(name).into_iter().flatten() {
+            visit(dependency, dependencies, visited, order)
+        }
+        order.push(name.to_string())
+    }
+    let mut visited = HashSet::new();
+    let mut order = Vec::new();
+    for name in names {
+        visit(name, dependencies, &mut visited, &mut order)
+    }
+    order
+}
+
+/// Whether `to` can be reached from the references of `from`.
+fn reaches(from: &str, to: &str, dependencies: &HashMap<String, Vec<String>>) -> bool {
+    let mut visited = HashSet::new();
+    let mut pending: Vec<&str> = dependencies
+        .get(from)
+        .into_iter()
+        .flatten()
+        .map(String::as_str)
+        .collect();
+    while let Some(name) = pending.pop() {
+        if name == to {
+            return true;
+        }
+        if visited.insert(name) {
+            pending.extend(
+                dependencies
+                    .get(name)
+                    .into_iter()
+                    .flatten()
+                    .map(String::as_str),
+            )
+        }
+    }
+    false
+}
+
+/// What the validator of a declaration knows about the other declarations.
+struct Scope<'d> {
+    /// The declared types, referenced through their validator.
+    declared: &'d HashSet<String>,
+    /// The generic types, whose validators are functions of the validators of their type
+    /// arguments.
+    generic: &'d HashSet<String>,
+    /// The type parameters of the declaration, which are validators passed as arguments.
+    type_parameters: Vec<String>,
+}
+
+/// The runtime validation libraries (zod, io-ts), whose validators are constants declared along
+/// with the type they check. Implementations only name the combinators of their library.
+trait RuntimeValidators {
+    fn options(&self) -> &Options;
+    /// What the library is imported as and its module, e.g. `* as t` & `io-ts`.
+    fn library(&self) -> (&'static str, &'static str);
+    /// The constant holding the validator of a declared type.
+    fn validator_name(&self, name: &str) -> String;
+    /// The type of a validator of `type_expression`, the annotation of recursive validators.
+    fn validator_type(&self, type_expression: &str) -> String;
+    /// The type checked by the validator held by `constant`.
+    fn inferred_type(&self, constant: &str) -> String;
+    /// The bound of the validators passed to generic ones.
+    fn type_parameter_bound(&self) -> &'static str;
+    /// Defers the evaluation of the validator of the recursive type `name`.
+    fn lazy(&self, name: &str, validator: String) -> String;
+    /// The validator of a basic type, declared types are referred to by `reference`.
+    fn data_type(&self, data_type: ast::DataType, scope: &Scope) -> Option<String>;
+    fn known_type(&self, json: registry::JsonKind, format: Option<&str>) -> String;
+    /// Anything goes at runtime for mapped types, which are described in TypeScript.
+    fn mapped_type(&self, type_expression: &str) -> String;
+    fn unknown(&self) -> String;
+    fn array(&self, element: String, length: Option<usize>) -> String;
+    fn nullable(&self, validator: String) -> String;
+    /// Object keys are always strings, whatever the type of the map keys.
+    fn record(&self, value: String) -> String;
+    /// The properties come along with whether they are optional, `extended` objects embed types
+    /// that aren't declared in the input and so have fields that aren't known.
+    fn object(&self, properties: Vec<(String, bool)>, extended: bool) -> String;
+    /// The literals of the constants of a type, `strings` when all of them are strings.
+    fn enumeration(&self, literals: Vec<String>, strings: bool) -> String;
+
+    fn printer(&self) -> Printer<'_> {
+        Printer::new(&self.options().formatting, Separator::Comma)
+    }
+
+    /// Writes the types that can't be inferred, those of recursive and generic validators.
+    fn typescript(&self) -> TypeScriptInterpreter {
+        TypeScriptInterpreter::new(Options {
+            typescript: TypeScriptOptions {
+                declarations: DeclarationStyle::TypeAlias,
+                exports: ExportStyle::Named,
+                ..self.options().typescript.clone()
+            },
+            ..self.options().clone()
+        })
+    }
+
+    fn interpret_validators(&self, ast: Vec<AST>) -> Result<String, InterpreterError> {
+        let (binding, module) = self.library();
+        let library_import = format!(
+            "import {} from {};",
+            binding,
+            self.printer()
+                .literal(&ast::ConstValue::String(module.to_string()))
+        );
+        let imports = mapped_imports(self.options(), &ast);
+        let mut sections = vec![[library_import, imports].join("\n").trim_end().to_string()];
+
+        let mut declarations = HashMap::new();
+        let mut names = Vec::new();
+        let mut generic = HashSet::new();
+        for item in ast {
+            let (name, type_parameters) = match &item {
+                AST::Declaration(declaration) => (&declaration.name, &declaration.type_parameters),
+                AST::Definition(declaration) | AST::Alias(declaration) => {
+                    (&declaration.name, &declaration.type_parameters)
+                }
+                AST::Constant(_) => continue,
+                _ => return Err(InterpreterError::ExpectedStructFoundField),
+            };
+            if !type_parameters.is_empty() {
+                generic.insert(name.clone());
+            }
+            names.push(name.clone());
+            declarations.insert(name.clone(), item);
+        }
+        let declared: HashSet<String> = declarations.keys().cloned().collect();
+        let dependencies: HashMap<String, Vec<String>> = declarations
+            .iter()
+            .map(|(name, item)| (name.clone(), references(item, &declared)))
+            .collect();
+
+        for name in declaration_order(&names, &dependencies) {
+            let recursive = reaches(&name, &name, &dependencies);
+            let section = match declarations.remove(&name) {
+                Some(AST::Declaration(declaration)) => {
+                    let scope = Scope {
+                        declared: &declared,
+                        generic: &generic,
+                        type_parameters: parameter_names(&declaration.type_parameters),
+                    };
+                    self.interpret_struct(*declaration, &scope, recursive)
+                }
+                Some(AST::Definition(declaration)) | Some(AST::Alias(declaration)) => {
+                    let scope = Scope {
+                        declared: &declared,
+                        generic: &generic,
+                        type_parameters: parameter_names(&declaration.type_parameters),
+                    };
+                    self.interpret_type_declaration(*declaration, &scope, recursive)
+                }
+                _ => continue,
+            };
+            sections.push(section)
+        }
+        Ok(self.printer().document(sections))
+    }
+
+    fn interpret_struct(
+        &self,
+        declaration: ast::StructDeclaration,
+        scope: &Scope,
+        recursive: bool,
+    ) -> String {
+        let name = declaration.name.clone();
+        let validator = self.interpret_struct_body(&declaration.body, scope);
+        let explicit_type = if recursive || !scope.type_parameters.is_empty() {
+            Some(self.typescript().interpret_struct(declaration.clone()))
+        } else {
+            None
+        };
+        self.interpret_validator(
+            &name,
+            declaration.doc,
+            validator,
+            explicit_type,
+            scope,
+            recursive,
+        )
+    }
+
+    fn interpret_type_declaration(
+        &self,
+        declaration: ast::TypeDeclaration,
+        scope: &Scope,
+        recursive: bool,
+    ) -> String {
+        let name = declaration.name.clone();
+        let doc = declaration.doc.clone();
+        let validator = match self.interpret_type_definition(&declaration, scope) {
+            Some(validator) => validator,
+            None => return String::new(),
+        };
+        let explicit_type = if recursive || !scope.type_parameters.is_empty() {
+            Some(self.typescript().interpret_type_declaration(declaration))
+        } else {
+            None
+        };
+        self.interpret_validator(&name, doc, validator, explicit_type, scope, recursive)
+    }
+
+    fn interpret_type_definition(
+        &self,
+        declaration: &ast::TypeDeclaration,
+        scope: &Scope,
+    ) -> Option<String> {
+        if let Some(literals) = enum_literals(&self.printer(), &declaration.variants) {
+            let strings = declaration
+                .variants
+                .iter()
+                .all(|variant| matches!(variant.value, Some(ast::ConstValue::String(_))));
+            return Some(self.enumeration(literals, strings));
+        }
+        self.convert_field_type(declaration.definition.clone(), scope)
+    }
+
+    /// Declares the validator along with its type, recursive validators are evaluated lazily and
+    /// generic ones are functions of the validators of their type arguments.
+    fn interpret_validator(
+        &self,
+        name: &str,
+        doc: Option<String>,
+        validator: String,
+        explicit_type: Option<String>,
+        scope: &Scope,
+        recursive: bool,
+    ) -> String {
+        let validator = if recursive {
+            self.lazy(name, validator)
+        } else {
+            validator
+        };
+        let (annotation, validator) = if scope.type_parameters.is_empty() {
+            let annotation = if recursive {
+                format!(": {}", self.validator_type(name))
+            } else {
+                String::new()
+            };
+            (annotation, validator)
+        } else {
+            let bounds: Vec<String> = scope
+                .type_parameters
+                .iter()
+                .map(|parameter| format!("{} extends {}", parameter, self.type_parameter_bound()))
+                .collect();
+            let parameters: Vec<String> = scope
+                .type_parameters
+                .iter()
+                .map(|parameter| format!("{}: {}", parameter, parameter))
+                .collect();
+            let validator = format!(
+                "<{}>({}) => {}",
+                bounds.join(", "),
+                parameters.join(", "),
+                validator
+            );
+            (String::new(), validator)
+        };
+        let constant = self.validator_name(name);
+        let declaration = format!("export const {}{} = {};", constant, annotation, validator);
+        match explicit_type {
+            Some(explicit_type) => format!("{}\n{}", explicit_type, declaration),
+            None => format!(
+                "{}{}\nexport type {} = {};",
+                declaration_doc(doc.as_deref()),
+                declaration,
+                name,
+                self.inferred_type(&constant)
+            ),
+        }
+    }
+
+    fn interpret_struct_body(&self, body: &[ast::Field], scope: &Scope) -> String {
+        let properties: Vec<(String, bool)> = body
+            .iter()
+            .cloned()
+            .filter_map(|field| self.interpret_field(field, scope))
+            .collect();
+        self.object(properties, !embedded_types(body).is_empty())
+    }
+
+    /// The property along with whether it's optional.
+    fn interpret_field(&self, field: ast::Field, scope: &Scope) -> Option<(String, bool)> {
+        match field {
+            ast::Field::Blank => None,
+            ast::Field::Documented(doc, field) => self
+                .interpret_field(*field, scope)
+                .map(|(field, optional)| (format!("{}\n{}", doc_comment(&doc), field), optional)),
+            ast::Field::Plain(field_name, field_type) => {
+                self.interpret_property(field_name.0, field_type, ast::JsonTag::default(), scope)
+            }
+            ast::Field::WithTags(field_name, field_type, tags) => self.interpret_property(
+                field_name.0,
+                field_type,
+                ast::JsonTag::from_tags(&tags),
+                scope,
+            ),
+        }
+    }
+
+    fn interpret_property(
+        &self,
+        field_name: String,
+        field_type: ast::FieldType,
+        json_tag: ast::JsonTag,
+        scope: &Scope,
+    ) -> Option<(String, bool)> {
+        if json_tag.skip {
+            return None;
+        }
+        let field_type = if json_tag.as_string {
+            quoted_type(field_type)
+        } else {
+            field_type
+        };
+        let is_pointer = matches!(field_type, ast::FieldType::Pointer(..));
+        let optional = json_tag.omit_empty || (self.options().optional_pointers && is_pointer);
+        let validator = self.convert_field_type(field_type, scope)?;
+        let name = self
+            .printer()
+            .property_key(json_tag.name.unwrap_or(field_name));
+        Some((format!("{}: {}", name, validator), optional))
+    }
+
+    fn convert_field_type(&self, field_type: ast::FieldType, scope: &Scope) -> Option<String> {
+        if let Some(mapping) = mapped_type(self.options(), &field_type) {
+            return Some(self.mapped_type(&mapping.type_expression));
+        }
+        if let Some(known_type) = registry::known_type(&field_type) {
+            let validator = self.known_type(known_type.json, known_type.format);
+            return Some(if known_type.nullable {
+                self.nullable(validator)
+            } else {
+                validator
+            });
+        }
+        match field_type {
+            ast::FieldType::Named(data_type) => self.data_type(data_type, scope),
+            ast::FieldType::Qualified(..) => Some(self.unknown()),
+            ast::FieldType::Slice(element_type) => self
+                .convert_field_type(*element_type, scope)
+                .map(|element| self.array(element, None)),
+            ast::FieldType::Array(length, element_type) => self
+                .convert_field_type(*element_type, scope)
+                .map(|element| self.array(element, length.parse().ok())),
+            ast::FieldType::Pointer(pointee) => {
+                let nullable = is_nullable(&pointee);
+                self.convert_field_type(*pointee, scope).map(|pointee| {
+                    if nullable {
+                        pointee
+                    } else {
+                        self.nullable(pointee)
+                    }
+                })
+            }
+            ast::FieldType::Map(_, value_type) => self
+                .convert_field_type(*value_type, scope)
+                .map(|value| self.record(value)),
+            ast::FieldType::Instantiated(generic_type, type_arguments) => {
+                self.convert_instantiated_type(*generic_type, type_arguments, scope)
+            }
+            ast::FieldType::Struct(declaration) => {
+                Some(self.interpret_struct_body(&declaration.body, scope))
+            }
+        }
+    }
+
+    fn convert_instantiated_type(
+        &self,
+        generic_type: ast::FieldType,
+        type_arguments: Vec<ast::FieldType>,
+        scope: &Scope,
+    ) -> Option<String> {
+        match generic_type {
+            ast::FieldType::Named(ast::DataType::Custom(name)) if scope.generic.contains(&name) => {
+                let type_arguments: Vec<String> = type_arguments
+                    .into_iter()
+                    .filter_map(|type_argument| self.convert_field_type(type_argument, scope))
+                    .collect();
+                Some(format!(
+                    "{}({})",
+                    self.validator_name(&name),
+                    type_arguments.join(", ")
+                ))
+            }
+            generic_type => self.convert_field_type(generic_type, scope),
+        }
+    }
+
+    /// The validator of a type parameter or declared type, there's none for the types that aren't
+    /// declared in the input.
+    fn reference(&self, name: String, scope: &Scope) -> String {
+        if scope.type_parameters.contains(&name) {
+            name
+        } else if scope.declared.contains(&name) {
+            self.validator_name(&name)
+        } else {
+            self.unknown()
+        }
+    }
+}
+
 #[derive(PartialEq, Debug)]
 pub enum InterpreterError {
     ExpectedStructFoundField,
@@ -26,7 +735,7 @@ impl Display for InterpreterError {
             InterpreterError::UnexpectedInterpreterName(namegiven) => {
                 write!(
                     f,
-                    "You have provided {}. Please provide either `flow` | `typescript` ",
+                    "You have provided {}. Please provide either `flow` | `typescript` | `jsonschema` | `zod` | `io-ts` | `rust` ",
                     namegiven
                 )
             }
@@ -42,20 +751,37 @@ impl From<InterpreterError> for Vec<String> {
 
 pub trait Interpreter {
     fn interpret(&self, ast: Vec<AST>) -> Result<String, InterpreterError>;
+
+    /// Whether the embedded structs declared in the input are kept for the interpreter to
+    /// flatten, rather than having their fields inlined.
+    fn flattens_embedded_structs(&self) -> bool {
+        false
+    }
 }
 
 pub trait ToInterpreter {
-    fn convert(&self) -> Result<Box<dyn Interpreter>, InterpreterError>;
+    fn convert_with(&self, options: &Options) -> Result<Box<dyn Interpreter>, InterpreterError>;
+
+    fn convert(&self) -> Result<Box<dyn Interpreter>, InterpreterError> {
+        self.convert_with(&Options::default())
+    }
 }
 
 macro_rules! interpreter_impl_for {
     ($t:ty) => {
         impl ToInterpreter for $t {
-            fn convert(&self) -> Result<Box<dyn Interpreter>, InterpreterError> {
+            fn convert_with(
+                &self,
+                options: &Options,
+            ) -> Result<Box<dyn Interpreter>, InterpreterError> {
                 let name = self.to_ascii_lowercase();
                 match name.as_ref() {
-                    "flow" => Ok(Box::new(FlowInterpreter::new())),
-                    "typescript" => Ok(Box::new(TypeScriptInterpreter::new())),
+                    "flow" => Ok(Box::new(FlowInterpreter::new(options.clone()))),
+                    "typescript" => Ok(Box::new(TypeScriptInterpreter::new(options.clone()))),
+                    "jsonschema" => Ok(Box::new(JsonSchemaInterpreter::new(options.clone()))),
+                    "zod" => Ok(Box::new(ZodInterpreter::new(options.clone()))),
+                    "io-ts" => Ok(Box::new(IoTsInterpreter::new(options.clone()))),
+                    "rust" => Ok(Box::new(RustInterpreter::new(options.clone()))),
                     _ => return Err(InterpreterError::UnexpectedInterpreterName(name)),
                 }
             }
@@ -72,6 +798,14 @@ mod tests {
 
     use super::ToInterpreter;
 
+    #[test]
+    fn go_string_escapes_should_be_decoded() {
+        assert_eq!(
+            super::unescape_go_string(r#"\"a\tb\\\x41\101\u00e9\U0001F600\xc3\xa9"#),
+            "\"a\tb\\AAé😀é"
+        );
+    }
+
     #[test]
     fn invalid_interpreter_name_should_return_error() {
         let target = "glow".to_string();
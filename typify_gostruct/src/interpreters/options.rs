@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+
+/// Options that tweak the output of the interpreters.
+///
+/// # Examples
+/// ```
+/// use typify_gostruct::{EnumStyle, Options};
+/// let mut options = Options::default();
+/// options.typescript.enums = EnumStyle::Enum;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    pub large_integers: LargeIntegerStyle,
+    /// Whether pointer fields are also optional properties, on top of being nullable.
+    pub optional_pointers: bool,
+    /// Whether unexported (lower case) fields are kept even though encoding/json ignores them.
+    pub keep_unexported_fields: bool,
+    /// Go type names, qualified (`money.Amount`) or not (`TenantID`), and what they become.
+    pub type_mappings: HashMap<String, TypeMapping>,
+    pub typescript: TypeScriptOptions,
+    pub flow: FlowOptions,
+    pub formatting: Formatting,
+}
+
+impl Options {
+    /// Returns the options with `go_type` mapped to `mapping` by every interpreter.
+    ///
+    /// # Examples
+    /// ```
+    /// use typify_gostruct::{Options, TypeMapping};
+    /// let options = Options::default().with_type_mapping(
+    ///     "money.Amount",
+    ///     TypeMapping::new("Amount").with_import(r#"import { Amount } from "@acme/money";"#),
+    /// );
+    /// ```
+    pub fn with_type_mapping(mut self, go_type: &str, mapping: TypeMapping) -> Self {
+        self.type_mappings.insert(go_type.to_string(), mapping);
+        self
+    }
+}
+
+/// A target-language type expression that replaces a Go type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeMapping {
+    pub type_expression: String,
+    /// Emitted once at the top of the output when the type is used.
+    pub import: Option<String>,
+    /// The JSON schema of the type, emitted as is. The type accepts any value when it's missing.
+    pub json_schema: Option<String>,
+    /// The Rust type, `serde_json::Value` when it's missing.
+    pub rust: Option<String>,
+}
+
+impl TypeMapping {
+    pub fn new(type_expression: &str) -> Self {
+        Self {
+            type_expression: type_expression.to_string(),
+            import: None,
+            json_schema: None,
+            rust: None,
+        }
+    }
+
+    pub fn with_import(mut self, import: &str) -> Self {
+        self.import = Some(import.to_string());
+        self
+    }
+
+    /// # Examples
+    /// ```
+    /// use typify_gostruct::TypeMapping;
+    /// let mapping = TypeMapping::new("Amount").with_json_schema(r#"{ "type": "string" }"#);
+    /// ```
+    pub fn with_json_schema(mut self, json_schema: &str) -> Self {
+        self.json_schema = Some(json_schema.to_string());
+        self
+    }
+
+    /// # Examples
+    /// ```
+    /// use typify_gostruct::TypeMapping;
+    /// let mapping = TypeMapping::new("Amount").with_rust("rust_decimal::Decimal");
+    /// ```
+    pub fn with_rust(mut self, rust_type: &str) -> Self {
+        self.rust = Some(rust_type.to_string());
+        self
+    }
+}
+
+/// Options that only apply to the `typescript` interpreter.
+#[derive(Debug, Clone, Default)]
+pub struct TypeScriptOptions {
+    pub enums: EnumStyle,
+    pub declarations: DeclarationStyle,
+    pub exports: ExportStyle,
+    /// Whether every property is `readonly`.
+    pub readonly: bool,
+}
+
+/// How structs are declared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DeclarationStyle {
+    /// `export interface Region { country: string }`
+    #[default]
+    Interface,
+    /// `export type Region = { country: string };`
+    TypeAlias,
+    /// `export declare interface Region { country: string }`, every declaration is ambient.
+    Declare,
+}
+
+/// How declarations are exported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExportStyle {
+    /// `export interface Region { ... }`
+    #[default]
+    Named,
+    /// Named exports, along with `export default Region;` for the first struct.
+    Default,
+    /// `interface Region { ... }`
+    None,
+}
+
+/// How types with `const` values are emitted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EnumStyle {
+    /// `export type Role = "admin" | "user";`
+    #[default]
+    Union,
+    /// `export enum Role { RoleAdmin = "admin", RoleUser = "user", }`
+    Enum,
+}
+
+/// Options that only apply to the `flow` interpreter, pointers are always maybe types (`?T`).
+#[derive(Debug, Clone, Default)]
+pub struct FlowOptions {
+    pub objects: ObjectStyle,
+    /// Whether every property is covariant (`+country: string`), i.e. read-only.
+    pub readonly: bool,
+    /// Whether slices and arrays are `$ReadOnlyArray<T>` rather than `T[]`.
+    pub readonly_arrays: bool,
+}
+
+/// How object types are delimited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ObjectStyle {
+    /// `{ country: string }`, exact by default since Flow 0.202 and inexact before.
+    #[default]
+    Implicit,
+    /// `{| country: string |}`, exact whatever the version of Flow.
+    Exact,
+}
+
+/// How `int64` and `uint64` are emitted, since JSON consumers lose precision above 2^53.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LargeIntegerStyle {
+    /// `number`
+    #[default]
+    Number,
+    /// `string`
+    String,
+    /// `bigint`
+    BigInt,
+}
+
+/// How the generated code is laid out, the defaults match Prettier's.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Formatting {
+    pub indent: Indent,
+    /// Separates the members of object types, `None` picks the target's convention.
+    pub separator: Option<Separator>,
+    pub quotes: QuoteStyle,
+    pub trailing_newline: bool,
+}
+
+impl Default for Formatting {
+    fn default() -> Self {
+        Self {
+            indent: Indent::Spaces(2),
+            separator: None,
+            quotes: QuoteStyle::Double,
+            trailing_newline: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Indent {
+    /// The number of spaces per level.
+    Spaces(usize),
+    Tabs,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Separator {
+    Semicolon,
+    Comma,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuoteStyle {
+    #[default]
+    Double,
+    Single,
+}
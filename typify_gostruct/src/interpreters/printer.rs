@@ -0,0 +1,181 @@
+use crate::ast::ConstValue;
+
+use super::options::{Formatting, Indent, QuoteStyle, Separator};
+
+static PRINT_WIDTH: usize = 80;
+
+/// Lays out the code generated by the interpreters according to the `Formatting` options.
+pub struct Printer<'f> {
+    formatting: &'f Formatting,
+    separator: Separator,
+}
+
+impl<'f> Printer<'f> {
+    /// `separator` is the target's own convention, used unless the formatting picks one.
+    pub fn new(formatting: &'f Formatting, separator: Separator) -> Self {
+        Self {
+            formatting,
+            separator: formatting.separator.unwrap_or(separator),
+        }
+    }
+
+    /// Joins the sections of a file (a header, the imports, each declaration) with blank lines.
+    pub fn document(&self, sections: Vec<String>) -> String {
+        let sections: Vec<String> = sections
+            .into_iter()
+            .filter(|section| !section.is_empty())
+            .collect();
+        let mut result = sections.join("\n\n");
+        if self.formatting.trailing_newline && !result.is_empty() {
+            result.push('\n')
+        }
+        result
+    }
+
+    /// An object type with one member per line, `{}` when it has none.
+    pub fn object(&self, members: Vec<String>) -> String {
+        self.block(("{", "}"), members, self.member_separator())
+    }
+
+    /// A Flow exact object type, `{| ... |}`.
+    pub fn exact_object(&self, members: Vec<String>) -> String {
+        self.block(("{|", "|}"), members, self.member_separator())
+    }
+
+    /// A JavaScript object literal, its members are always separated by commas.
+    pub fn object_literal(&self, members: Vec<String>) -> String {
+        self.block(("{", "}"), members, ",")
+    }
+
+    /// A JavaScript array literal with one element per line.
+    pub fn array_literal(&self, elements: Vec<String>) -> String {
+        self.block(("[", "]"), elements, ",")
+    }
+
+    /// The body of an enum, its members are always separated by commas.
+    pub fn enum_body(&self, members: Vec<String>) -> String {
+        self.block(("{", "}"), members, ",")
+    }
+
+    /// A JSON object or array with one member per line, JSON takes no trailing separator.
+    pub fn json_block(&self, (open, close): (&str, &str), members: Vec<String>) -> String {
+        if members.is_empty() {
+            return format!("{}{}", open, close);
+        }
+        let members: Vec<String> = members.iter().map(|member| self.indent(member)).collect();
+        format!("{}\n{}\n{}", open, members.join(",\n"), close)
+    }
+
+    /// Whether `line`, indented `depth` levels, fits in Prettier's default print width.
+    pub fn fits(&self, depth: usize, line: &str) -> bool {
+        let width = match self.formatting.indent {
+            Indent::Spaces(width) => width,
+            // Prettier counts a tab as its default tab width.
+            Indent::Tabs => 2,
+        };
+        depth * width + line.chars().count() <= PRINT_WIDTH
+    }
+
+    fn member_separator(&self) -> &'static str {
+        match self.separator {
+            Separator::Semicolon => ";",
+            Separator::Comma => ",",
+        }
+    }
+
+    fn block(&self, (open, close): (&str, &str), members: Vec<String>, separator: &str) -> String {
+        if members.is_empty() {
+            return format!("{}{}", open, close);
+        }
+        let mut result = format!("{}\n", open);
+        for member in members {
+            result.push_str(&self.indent(&member));
+            result.push_str(separator);
+            result.push('\n');
+        }
+        result.push_str(close);
+        result
+    }
+
+    /// Indents every line of `code` by one level, nested blocks keep their relative indentation.
+    pub fn indent(&self, code: &str) -> String {
+        let indentation = match self.formatting.indent {
+            Indent::Spaces(width) => " ".repeat(width),
+            Indent::Tabs => "\t".to_string(),
+        };
+        code.lines()
+            .map(|line| {
+                if line.is_empty() {
+                    String::new()
+                } else {
+                    format!("{}{}", indentation, line)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    pub fn literal(&self, value: &ConstValue) -> String {
+        match value {
+            ConstValue::String(literal) => self.go_string(literal),
+            ConstValue::Integer(value) => value.to_string(),
+        }
+    }
+
+    /// Requotes the content of a Go string literal. Its escape sequences are decoded first since
+    /// some of them (octal ones for instance) aren't valid in strict mode JavaScript.
+    fn go_string(&self, literal: &str) -> String {
+        let quote = self.quote_character();
+        let mut result = quote.to_string();
+        for character in super::unescape_go_string(literal).chars() {
+            match character {
+                '\\' => result.push_str("\\\\"),
+                '\n' => result.push_str("\\n"),
+                '\r' => result.push_str("\\r"),
+                '\t' => result.push_str("\\t"),
+                character if character == quote => {
+                    result.push('\\');
+                    result.push(character)
+                }
+                // Line and paragraph separators end lines in older JavaScript engines.
+                character
+                    if character.is_control() || matches!(character, '\u{2028}' | '\u{2029}') =>
+                {
+                    result.push_str(&format!("\\u{:04x}", character as u32))
+                }
+                character => result.push(character),
+            }
+        }
+        result.push(quote);
+        result
+    }
+
+    /// JSON names that aren't identifiers (`-`, `first-name`, ...) have to be quoted.
+    pub fn property_key(&self, name: String) -> String {
+        let mut characters = name.chars();
+        let is_identifier = characters
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+            && characters.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+        if is_identifier {
+            return name;
+        }
+        let quote = self.quote_character();
+        let mut result = quote.to_string();
+        for character in name.chars() {
+            if character == quote || character == '\\' {
+                result.push('\\')
+            }
+            result.push(character)
+        }
+        result.push(quote);
+        result
+    }
+
+    fn quote_character(&self) -> char {
+        match self.formatting.quotes {
+            QuoteStyle::Double => '"',
+            QuoteStyle::Single => '\'',
+        }
+    }
+}
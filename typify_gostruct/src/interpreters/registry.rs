@@ -0,0 +1,140 @@
+use crate::ast::{DataType, FieldType};
+
+/// The JSON value a known type marshals to, which the schema-based targets build on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonKind {
+    Any,
+    String,
+    Number,
+    Integer,
+    Boolean,
+}
+
+/// The target types of a Go type whose JSON encoding is well known.
+pub struct KnownType {
+    pub typescript: &'static str,
+    pub flow: &'static str,
+    pub json: JsonKind,
+    /// The JSON Schema `format` of strings, e.g. `date-time`.
+    pub format: Option<&'static str>,
+    /// Whether the type marshals to `null` when it's not valid.
+    pub nullable: bool,
+}
+
+impl KnownType {
+    const fn new(typescript: &'static str, flow: &'static str, json: JsonKind) -> Self {
+        Self {
+            typescript,
+            flow,
+            json,
+            format: None,
+            nullable: false,
+        }
+    }
+
+    const fn nullable(mut self) -> Self {
+        self.nullable = true;
+        self
+    }
+
+    const fn format(mut self, format: &'static str) -> Self {
+        self.format = Some(format);
+        self
+    }
+}
+
+static BYTES: KnownType = KnownType::new("string", "string", JsonKind::String);
+
+static KNOWN_TYPES: &[(&str, KnownType)] = &[
+    ("any", KnownType::new("unknown", "mixed", JsonKind::Any)),
+    ("error", KnownType::new("unknown", "mixed", JsonKind::Any)),
+    (
+        "time.Time",
+        KnownType::new("string", "string", JsonKind::String).format("date-time"),
+    ),
+    (
+        "time.Duration",
+        KnownType::new("number", "number", JsonKind::Integer),
+    ),
+    (
+        "json.RawMessage",
+        KnownType::new("unknown", "mixed", JsonKind::Any),
+    ),
+    (
+        "uuid.UUID",
+        KnownType::new("string", "string", JsonKind::String).format("uuid"),
+    ),
+    (
+        "decimal.Decimal",
+        KnownType::new("string", "string", JsonKind::String),
+    ),
+    (
+        "big.Int",
+        KnownType::new("number", "number", JsonKind::Integer),
+    ),
+    // Unlike `big.Int`, these are text marshalers.
+    (
+        "big.Float",
+        KnownType::new("string", "string", JsonKind::String),
+    ),
+    (
+        "big.Rat",
+        KnownType::new("string", "string", JsonKind::String),
+    ),
+    (
+        "sql.NullString",
+        KnownType::new("string", "string", JsonKind::String).nullable(),
+    ),
+    (
+        "sql.NullInt16",
+        KnownType::new("number", "number", JsonKind::Integer).nullable(),
+    ),
+    (
+        "sql.NullInt32",
+        KnownType::new("number", "number", JsonKind::Integer).nullable(),
+    ),
+    (
+        "sql.NullInt64",
+        KnownType::new("number", "number", JsonKind::Integer).nullable(),
+    ),
+    (
+        "sql.NullFloat64",
+        KnownType::new("number", "number", JsonKind::Number).nullable(),
+    ),
+    (
+        "sql.NullByte",
+        KnownType::new("number", "number", JsonKind::Integer).nullable(),
+    ),
+    (
+        "sql.NullBool",
+        KnownType::new("boolean", "boolean", JsonKind::Boolean).nullable(),
+    ),
+    (
+        "sql.NullTime",
+        KnownType::new("string", "string", JsonKind::String)
+            .format("date-time")
+            .nullable(),
+    ),
+];
+
+/// Looks up the standard-library and ecosystem types, including `any` (and so `interface{}`)
+/// and `[]byte` which encoding/json marshals as a base64 string.
+pub fn known_type(field_type: &FieldType) -> Option<&'static KnownType> {
+    match field_type {
+        FieldType::Named(DataType::Custom(name)) => lookup(name),
+        FieldType::Qualified(package, name) => lookup(&format!("{}.{}", package, name)),
+        FieldType::Slice(element_type)
+            if matches!(**element_type, FieldType::Named(DataType::Byte)) =>
+        {
+            Some(&BYTES)
+        }
+        _ => None,
+    }
+}
+
+fn lookup(name: &str) -> Option<&'static KnownType> {
+    KNOWN_TYPES
+        .iter()
+        .find(|(known_name, _)| *known_name == name)
+        .map(|(_, known_type)| known_type)
+}
@@ -0,0 +1,471 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+
+use crate::ast::{
+    ConstValue, DataType, Field, FieldType, JsonTag, StructDeclaration, TypeDeclaration,
+    TypeParameter, AST,
+};
+
+use super::printer::Printer;
+use super::registry::{self, JsonKind, KnownType};
+use super::{Formatting, Indent, Interpreter, InterpreterError, Options, Separator};
+
+static RESERVED_WORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let", "loop",
+    "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "static",
+    "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual",
+    "where", "while", "yield",
+];
+
+/// Keywords that can't be raw identifiers either.
+static PATH_KEYWORDS: &[&str] = &["crate", "self", "super"];
+
+pub struct RustInterpreter {
+    options: Options,
+    formatting: Formatting,
+}
+
+/// Where a type is interpreted.
+struct Scope<'d> {
+    /// The top-level declaration, pointers back to it are boxed.
+    declaration: &'d str,
+    /// The name given to an anonymous struct found here.
+    name: String,
+    dependencies: &'d HashMap<String, Vec<String>>,
+    /// The anonymous structs found so far, declared after the top-level declaration.
+    anonymous_structs: &'d RefCell<Vec<StructDeclaration>>,
+}
+
+impl Scope<'_> {
+    fn nested(&self, name: &str) -> Self {
+        Scope {
+            name: format!("{}{}", self.name, pascal_case(name)),
+            ..*self
+        }
+    }
+}
+
+impl Interpreter for RustInterpreter {
+    fn interpret(&self, ast: Vec<AST>) -> Result<String, InterpreterError> {
+        let mut imports = vec!["use serde::{Deserialize, Serialize};"];
+        if uses_maps(&ast) {
+            imports.push("use std::collections::HashMap;")
+        }
+        let mut sections = vec![imports.join("\n")];
+
+        let declared: HashSet<String> = ast
+            .iter()
+            .filter_map(|item| match item {
+                AST::Declaration(declaration) => Some(declaration.name.clone()),
+                AST::Definition(declaration) | AST::Alias(declaration) => {
+                    Some(declaration.name.clone())
+                }
+                _ => None,
+            })
+            .collect();
+        let dependencies: HashMap<String, Vec<String>> = ast
+            .iter()
+            .filter_map(|item| match item {
+                AST::Declaration(declaration) => Some((declaration.name.clone(), item)),
+                AST::Definition(declaration) | AST::Alias(declaration) => {
+                    Some((declaration.name.clone(), item))
+                }
+                _ => None,
+            })
+            .map(|(name, item)| (name, super::references(item, &declared)))
+            .collect();
+
+        for item in ast {
+            let anonymous_structs = RefCell::new(Vec::new());
+            let (name, declaration) = match item {
+                AST::Declaration(declaration) => {
+                    let scope = Scope {
+                        declaration: &declaration.name,
+                        name: declaration.name.clone(),
+                        dependencies: &dependencies,
+                        anonymous_structs: &anonymous_structs,
+                    };
+                    let section = self.interpret_struct(&declaration, &scope);
+                    (declaration.name, section)
+                }
+                AST::Definition(declaration) | AST::Alias(declaration) => {
+                    let name = declaration.name.clone();
+                    let scope = Scope {
+                        declaration: &name,
+                        name: format!("{}Item", name),
+                        dependencies: &dependencies,
+                        anonymous_structs: &anonymous_structs,
+                    };
+                    let section = self.interpret_type_declaration(*declaration, &scope);
+                    (name, section)
+                }
+                AST::Constant(_) => continue,
+                _ => return Err(InterpreterError::ExpectedStructFoundField),
+            };
+            sections.push(declaration);
+            // Rust has no anonymous structs, they are declared after the declaration using them.
+            let mut index = 0;
+            loop {
+                let anonymous_struct = match anonymous_structs.borrow().get(index) {
+                    Some(anonymous_struct) => anonymous_struct.clone(),
+                    None => break,
+                };
+                let scope = Scope {
+                    declaration: &name,
+                    name: anonymous_struct.name.clone(),
+                    dependencies: &dependencies,
+                    anonymous_structs: &anonymous_structs,
+                };
+                sections.push(self.interpret_struct(&anonymous_struct, &scope));
+                index += 1;
+            }
+        }
+        Ok(self.printer().document(sections))
+    }
+
+    fn flattens_embedded_structs(&self) -> bool {
+        true
+    }
+}
+
+impl RustInterpreter {
+    pub fn new(options: Options) -> Self {
+        // rustfmt indents with 4 spaces, tabs are kept for `hard_tabs` users.
+        let indent = match options.formatting.indent {
+            Indent::Tabs => Indent::Tabs,
+            Indent::Spaces(_) => Indent::Spaces(4),
+        };
+        let formatting = Formatting {
+            indent,
+            ..options.formatting.clone()
+        };
+        Self {
+            options,
+            formatting,
+        }
+    }
+    fn printer(&self) -> Printer<'_> {
+        Printer::new(&self.formatting, Separator::Comma)
+    }
+    fn get_field_type(&self, data_type: DataType) -> Option<String> {
+        let field_type = match data_type {
+            // JSON has no limit on integers, unlike JavaScript numbers.
+            DataType::Integer { bits, signed } => integer_type(bits, signed),
+            DataType::LargeNumber { signed } => integer_type(64, signed),
+            DataType::Float => "f64".to_string(),
+            DataType::Byte => "u8".to_string(),
+            // encoding/json refuses to marshal complex numbers, their fields are left out.
+            DataType::Complex => return None,
+            DataType::String => "String".to_string(),
+            DataType::Boolean => "bool".to_string(),
+            DataType::Custom(name) => name,
+            DataType::Embedded => return None,
+        };
+        Some(field_type)
+    }
+
+    fn interpret_struct(&self, declaration: &StructDeclaration, scope: &Scope) -> String {
+        let mut result = doc_comment(declaration.doc.as_deref());
+        result.push_str("#[derive(Serialize, Deserialize)]\n");
+        let fields: Vec<String> = declaration
+            .body
+            .iter()
+            .cloned()
+            .filter_map(|field| self.interpret_field(field, scope))
+            .collect();
+        result += &format!(
+            "pub struct {}{} {}",
+            declaration.name,
+            type_parameters(&declaration.type_parameters),
+            self.printer().object_literal(fields)
+        );
+        result
+    }
+
+    fn interpret_type_declaration(&self, declaration: TypeDeclaration, scope: &Scope) -> String {
+        let mut result = doc_comment(declaration.doc.as_deref());
+        let variants: Option<Vec<(String, String)>> = declaration
+            .variants
+            .iter()
+            .map(|variant| match &variant.value {
+                Some(ConstValue::String(value)) => {
+                    Some((variant.name.clone(), super::unescape_go_string(value)))
+                }
+                _ => None,
+            })
+            .collect();
+        // Numeric constants need serde_repr, they stay plain numbers.
+        if let Some(variants) = variants.filter(|variants| !variants.is_empty()) {
+            let variants: Vec<String> = variants
+                .into_iter()
+                .map(|(name, value)| {
+                    let variant = pascal_case(&name);
+                    if variant == value {
+                        variant
+                    } else {
+                        format!("#[serde(rename = {:?})]\n{}", value, variant)
+                    }
+                })
+                .collect();
+            result += &format!(
+                "#[derive(Serialize, Deserialize)]\npub enum {} {}",
+                declaration.name,
+                self.printer().object_literal(variants)
+            );
+            return result;
+        }
+        match self.convert_field_type(declaration.definition, scope) {
+            Some(definition) => {
+                result += &format!(
+                    "pub type {}{} = {};",
+                    declaration.name,
+                    type_parameters(&declaration.type_parameters),
+                    definition
+                );
+                result
+            }
+            None => String::new(),
+        }
+    }
+
+    fn interpret_field(&self, field: Field, scope: &Scope) -> Option<String> {
+        match field {
+            Field::Blank => None,
+            Field::Documented(doc, field) => self
+                .interpret_field(*field, scope)
+                .map(|field| format!("{}{}", doc_comment(Some(&doc)), field)),
+            Field::Plain(field_name, field_type) => {
+                self.interpret_property(field_name.0, field_type, JsonTag::default(), scope)
+            }
+            Field::WithTags(field_name, field_type, tags) => {
+                self.interpret_property(field_name.0, field_type, JsonTag::from_tags(&tags), scope)
+            }
+        }
+    }
+
+    /// `omitempty` fields are optional, pointers are optional whenever they are nil and
+    /// serialized as `null` unless they are `omitempty` too.
+    fn interpret_property(
+        &self,
+        field_name: String,
+        field_type: FieldType,
+        json_tag: JsonTag,
+        scope: &Scope,
+    ) -> Option<String> {
+        if json_tag.skip {
+            return None;
+        }
+        if matches!(field_type, FieldType::Named(DataType::Embedded)) {
+            return Some(self.interpret_embedded_field(&field_name));
+        }
+        let field_type = if json_tag.as_string {
+            super::quoted_type(field_type)
+        } else {
+            field_type
+        };
+        let is_optional = super::is_nullable(&field_type);
+        let mut rust_type = self.convert_field_type(field_type, &scope.nested(&field_name))?;
+        if json_tag.omit_empty && !is_optional {
+            rust_type = format!("Option<{}>", rust_type)
+        }
+
+        let name = field_identifier(&snake_case(&field_name));
+        let json_name = json_tag.name.unwrap_or(field_name);
+        let mut attributes = Vec::new();
+        if name.trim_start_matches("r#") != json_name {
+            attributes.push(format!("rename = {:?}", json_name))
+        }
+        if json_tag.omit_empty {
+            attributes.push("skip_serializing_if = \"Option::is_none\"".to_string())
+        }
+        let mut result = String::new();
+        if !attributes.is_empty() {
+            result += &format!("#[serde({})]\n", attributes.join(", "))
+        }
+        result += &format!("pub {}: {}", name, rust_type);
+        Some(result)
+    }
+
+    /// Embedded types are flattened into the struct, the field is named after the type.
+    fn interpret_embedded_field(&self, type_name: &str) -> String {
+        let unqualified = type_name.rsplit('.').next().unwrap_or(type_name);
+        format!(
+            "#[serde(flatten)]\npub {}: {}",
+            field_identifier(&snake_case(unqualified)),
+            type_name.replace('.', "::")
+        )
+    }
+
+    fn convert_field_type(&self, field_type: FieldType, scope: &Scope) -> Option<String> {
+        // Mapped types without a Rust type of their own take any JSON value.
+        if let Some(mapping) = super::mapped_type(&self.options, &field_type) {
+            return Some(
+                mapping
+                    .rust
+                    .clone()
+                    .unwrap_or_else(|| "serde_json::Value".to_string()),
+            );
+        }
+        if let Some(known_type) = registry::known_type(&field_type) {
+            return Some(self.convert_known_type(known_type));
+        }
+        match field_type {
+            FieldType::Named(data_type) => self.get_field_type(data_type),
+            FieldType::Qualified(package, name) => Some(format!("{}::{}", package, name)),
+            FieldType::Slice(element_type) => self
+                .convert_field_type(*element_type, scope)
+                .map(|element| format!("Vec<{}>", element)),
+            FieldType::Array(length, element_type) => self
+                .convert_field_type(*element_type, scope)
+                .map(|element| match length.parse::<usize>() {
+                    Ok(length) => format!("[{}; {}]", element, length),
+                    Err(_) => format!("Vec<{}>", element),
+                }),
+            FieldType::Pointer(pointee) => self.convert_pointer_type(*pointee, scope),
+            FieldType::Map(key_type, value_type) => {
+                let key = self.convert_field_type(*key_type, scope)?;
+                self.convert_field_type(*value_type, scope)
+                    .map(|value| format!("HashMap<{}, {}>", key, value))
+            }
+            FieldType::Instantiated(generic_type, type_arguments) => {
+                let type_arguments: Vec<String> = type_arguments
+                    .into_iter()
+                    .filter_map(|type_argument| self.convert_field_type(type_argument, scope))
+                    .collect();
+                self.convert_field_type(*generic_type, scope)
+                    .map(|generic_type| format!("{}<{}>", generic_type, type_arguments.join(", ")))
+            }
+            FieldType::Struct(mut declaration) => {
+                declaration.name = scope.name.clone();
+                scope.anonymous_structs.borrow_mut().push(*declaration);
+                Some(scope.name.clone())
+            }
+        }
+    }
+
+    fn convert_known_type(&self, known_type: &KnownType) -> String {
+        let rust_type = match known_type.json {
+            JsonKind::Any => "serde_json::Value",
+            JsonKind::String => "String",
+            JsonKind::Number => "f64",
+            JsonKind::Integer => "i64",
+            JsonKind::Boolean => "bool",
+        };
+        if known_type.nullable {
+            format!("Option<{}>", rust_type)
+        } else {
+            rust_type.to_string()
+        }
+    }
+
+    /// Pointers back to the declaration (or a declaration that leads back to it) are boxed,
+    /// recursive types would have an infinite size otherwise.
+    fn convert_pointer_type(&self, pointee: FieldType, scope: &Scope) -> Option<String> {
+        if super::is_nullable(&pointee) {
+            return self.convert_field_type(pointee, scope);
+        }
+        let recursive = match &pointee {
+            FieldType::Named(DataType::Custom(name)) => {
+                name == scope.declaration
+                    || super::reaches(name, scope.declaration, scope.dependencies)
+            }
+            _ => false,
+        };
+        self.convert_field_type(pointee, scope).map(|pointee| {
+            if recursive {
+                format!("Option<Box<{}>>", pointee)
+            } else {
+                format!("Option<{}>", pointee)
+            }
+        })
+    }
+}
+
+fn uses_maps(ast: &[AST]) -> bool {
+    let mut uses_maps = false;
+    let mut visit = |field_type: &FieldType| {
+        if matches!(field_type, FieldType::Map(..)) {
+            uses_maps = true
+        }
+    };
+    for item in ast {
+        match item {
+            AST::Declaration(declaration) => super::visit_fields(&declaration.body, &mut visit),
+            AST::Definition(declaration) | AST::Alias(declaration) => {
+                super::visit_field_type(&declaration.definition, &mut visit)
+            }
+            AST::Constant(_) | AST::Field(_) => {}
+        }
+    }
+    uses_maps
+}
+
+fn doc_comment(doc: Option<&str>) -> String {
+    match doc {
+        Some(doc) => doc
+            .lines()
+            .map(|line| format!("{}\n", format!("/// {}", line).trim_end()))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+/// `i32` for `int32` and `u64` for `uint`.
+fn integer_type(bits: u8, signed: bool) -> String {
+    format!("{}{}", if signed { "i" } else { "u" }, bits)
+}
+
+fn type_parameters(type_parameters: &[TypeParameter]) -> String {
+    if type_parameters.is_empty() {
+        return String::new();
+    }
+    format!("<{}>", super::parameter_names(type_parameters).join(", "))
+}
+
+/// `CreatedAt` becomes `created_at` and `HTTPServer` becomes `http_server`.
+fn snake_case(name: &str) -> String {
+    let characters: Vec<char> = name.chars().collect();
+    let mut result = String::new();
+    for (index, &character) in characters.iter().enumerate() {
+        if !character.is_uppercase() {
+            result.push(character);
+            continue;
+        }
+        let previous = index.checked_sub(1).map(|index| characters[index]);
+        let next = characters.get(index + 1);
+        let starts_word = match previous {
+            Some(previous) if previous.is_lowercase() || previous.is_ascii_digit() => true,
+            Some(previous) if previous.is_uppercase() => next.is_some_and(|n| n.is_lowercase()),
+            _ => false,
+        };
+        if starts_word && !result.ends_with('_') {
+            result.push('_')
+        }
+        result.extend(character.to_lowercase())
+    }
+    result
+}
+
+/// `role_admin` and `RoleAdmin` both become `RoleAdmin`.
+fn pascal_case(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut characters = word.chars();
+            match characters.next() {
+                Some(first) => first.to_uppercase().chain(characters).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn field_identifier(name: &str) -> String {
+    if PATH_KEYWORDS.contains(&name) {
+        format!("{}_", name)
+    } else if RESERVED_WORDS.contains(&name) {
+        format!("r#{}", name)
+    } else {
+        name.to_string()
+    }
+}
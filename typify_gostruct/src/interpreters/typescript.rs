@@ -1,63 +1,223 @@
-use crate::ast::{DataType, Field, FieldType, StructDeclaration, TagKey, AST};
+use crate::ast::{
+    ConstDeclaration, DataType, Field, FieldType, JsonTag, StructDeclaration, TypeDeclaration,
+    TypeParameter, AST,
+};
 
-use super::{Interpreter, InterpreterError};
+use super::printer::Printer;
+use super::registry::{self, KnownType};
+use super::{
+    DeclarationStyle, EnumStyle, ExportStyle, Interpreter, InterpreterError, LargeIntegerStyle,
+    Options, Separator,
+};
 
-pub struct TypeScriptInterpreter();
-
-static OPENING_BRACKET: char = '{';
-
-static CLOSING_BRACKET: char = '}';
+pub struct TypeScriptInterpreter {
+    options: Options,
+}
 
 impl Interpreter for TypeScriptInterpreter {
     fn interpret(&self, ast: Vec<crate::ast::AST>) -> Result<String, InterpreterError> {
-        let mut result = String::new();
+        let mut sections = vec![super::mapped_imports(&self.options, &ast)];
+        let default_export = ast.iter().find_map(|item| match item {
+            AST::Declaration(declaration) => Some(declaration.name.clone()),
+            _ => None,
+        });
         for item in ast {
-            let struct_results = match item {
+            let declaration = match item {
                 AST::Declaration(declaration) => self.interpret_struct(*declaration),
+                AST::Definition(declaration) | AST::Alias(declaration) => {
+                    self.interpret_type_declaration(*declaration)
+                }
+                AST::Constant(_) => continue,
                 _ => return Err(InterpreterError::ExpectedStructFoundField),
             };
-            result.push_str(&struct_results)
+            sections.push(declaration)
         }
-        Ok(result)
+        if let (ExportStyle::Default, Some(name)) =
+            (self.options.typescript.exports, default_export)
+        {
+            sections.push(format!("export default {};", name))
+        }
+        Ok(self.printer().document(sections))
     }
 }
 
 impl TypeScriptInterpreter {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(options: Options) -> Self {
+        Self { options }
+    }
+    fn printer(&self) -> Printer<'_> {
+        Printer::new(&self.options.formatting, Separator::Semicolon)
     }
     fn get_field_type(&self, data_type: DataType) -> super::FieldType {
         match data_type {
-            DataType::Number => super::FieldType::Normal("number".to_string()),
+            DataType::Integer { .. } | DataType::Float | DataType::Byte => {
+                super::FieldType::Normal("number".to_string())
+            }
+            DataType::LargeNumber { .. } => super::FieldType::Normal(
+                super::large_integer_type(self.options.large_integers).to_string(),
+            ),
+            DataType::Complex => super::FieldType::Normal("never".to_string()),
             DataType::String => super::FieldType::Normal("string".to_string()),
             DataType::Boolean => super::FieldType::Normal("boolean".to_string()),
             DataType::Custom(custom) => super::FieldType::Normal(custom),
             DataType::Embedded => super::FieldType::Embedded,
         }
     }
-    fn interpret_struct(&self, declaration: StructDeclaration) -> String {
-        let mut result = format!("\n export interface {} = ", declaration.name);
-        result.push(OPENING_BRACKET);
+    /// The modifiers and keyword that start a declaration, e.g. `export declare interface `.
+    fn declaration_keyword(&self, keyword: &str) -> String {
+        let export = match self.options.typescript.exports {
+            ExportStyle::Named | ExportStyle::Default => "export ",
+            ExportStyle::None => "",
+        };
+        let declare = match self.options.typescript.declarations {
+            DeclarationStyle::Declare => "declare ",
+            DeclarationStyle::Interface | DeclarationStyle::TypeAlias => "",
+        };
+        format!("{}{}{} ", export, declare, keyword)
+    }
 
-        for item in declaration.body {
-            let field_result = self.interpret_field(item);
-            result.push_str(&field_result)
+    pub(super) fn interpret_struct(&self, declaration: StructDeclaration) -> String {
+        let mut result = super::declaration_doc(declaration.doc.as_deref());
+        let type_parameters = self.interpret_type_parameters(declaration.type_parameters);
+        let mut embedded_types = super::extended_types(&self.options, &declaration.body);
+        if self.options.typescript.declarations == DeclarationStyle::TypeAlias {
+            embedded_types.push(self.interpret_struct_body(declaration.body));
+            result += &format!(
+                "{}{}{} = {};",
+                self.declaration_keyword("type"),
+                declaration.name,
+                type_parameters,
+                embedded_types.join(" & ")
+            );
+            return result;
         }
-        result.push(CLOSING_BRACKET);
+        let extends = if embedded_types.is_empty() {
+            String::new()
+        } else {
+            format!(" extends {}", embedded_types.join(", "))
+        };
+        result += &format!(
+            "{}{}{}{} ",
+            self.declaration_keyword("interface"),
+            declaration.name,
+            type_parameters,
+            extends
+        );
+        result.push_str(&self.interpret_struct_body(declaration.body));
         result
     }
 
+    pub(super) fn interpret_type_declaration(&self, declaration: TypeDeclaration) -> String {
+        let doc = super::declaration_doc(declaration.doc.as_deref());
+        match self.interpret_type_definition(declaration) {
+            definition if definition.is_empty() => definition,
+            definition => doc + &definition,
+        }
+    }
+
+    fn interpret_type_definition(&self, declaration: TypeDeclaration) -> String {
+        if let Some(literals) = super::enum_literals(&self.printer(), &declaration.variants) {
+            return match self.options.typescript.enums {
+                EnumStyle::Union => {
+                    format!(
+                        "{}{} = {};",
+                        self.declaration_keyword("type"),
+                        declaration.name,
+                        literals.join(" | ")
+                    )
+                }
+                EnumStyle::Enum => self.interpret_enum(declaration.name, declaration.variants),
+            };
+        }
+        match self.convert_field_type(declaration.definition) {
+            super::FieldType::Normal(definition) => format!(
+                "{}{}{} = {};",
+                self.declaration_keyword("type"),
+                declaration.name,
+                self.interpret_type_parameters(declaration.type_parameters),
+                definition
+            ),
+            super::FieldType::Embedded => String::new(),
+        }
+    }
+
+    fn interpret_enum(&self, name: String, variants: Vec<ConstDeclaration>) -> String {
+        let members: Vec<String> = variants
+            .into_iter()
+            .filter_map(|variant| {
+                let value = variant.value?;
+                Some(format!(
+                    "{} = {}",
+                    variant.name,
+                    self.printer().literal(&value)
+                ))
+            })
+            .collect();
+        format!(
+            "{}{} {}",
+            self.declaration_keyword("enum"),
+            name,
+            self.printer().enum_body(members)
+        )
+    }
+
+    fn interpret_type_parameters(&self, type_parameters: Vec<TypeParameter>) -> String {
+        if type_parameters.is_empty() {
+            return String::new();
+        }
+        let type_parameters: Vec<String> = type_parameters
+            .into_iter()
+            .map(|parameter| {
+                let mut constraint: Vec<String> = Vec::new();
+                for data_type in parameter.constraint {
+                    if let super::FieldType::Normal(data_type) = self.get_field_type(data_type) {
+                        if !constraint.contains(&data_type) {
+                            constraint.push(data_type)
+                        }
+                    }
+                }
+                if constraint.is_empty() {
+                    parameter.name
+                } else {
+                    format!("{} extends {}", parameter.name, constraint.join(" | "))
+                }
+            })
+            .collect();
+        format!("<{}>", type_parameters.join(", "))
+    }
+
+    fn interpret_struct_body(&self, body: Vec<Field>) -> String {
+        let properties: Vec<String> = body
+            .into_iter()
+            .map(|item| self.interpret_field(item))
+            .filter(|property| !property.is_empty())
+            .collect();
+        self.printer().object(properties)
+    }
+
     fn interpret_field(&self, field: crate::ast::Field) -> String {
         let mut result = String::new();
         let field_result = match field {
             Field::Blank => String::new(),
+            Field::Documented(doc, field) => match self.interpret_field(*field) {
+                field if field.is_empty() => field,
+                field => format!("{}\n{}", super::doc_comment(&doc), field),
+            },
             Field::Plain(field_name, field_type) => {
+                let property_name = super::property_name(
+                    &self.options,
+                    &self.printer(),
+                    field_name.0,
+                    &field_type,
+                    false,
+                );
                 let field_type = self.convert_field_type(field_type);
                 match field_type {
                     super::FieldType::Normal(field_type) => {
-                        format!("{} : {},", field_name.0, field_type)
+                        format!("{}{}: {}", self.readonly(), property_name, field_type)
                     }
-                    super::FieldType::Embedded => format!("...{}, ", field_name.0),
+                    // Embedded types are extended instead.
+                    super::FieldType::Embedded => String::new(),
                 }
             }
             Field::WithTags(field_name, field_type, field_tags) => {
@@ -69,22 +229,110 @@ impl TypeScriptInterpreter {
         result
     }
 
-    fn convert_field_type(&self, field_type: FieldType) -> super::FieldType {
-        let single_or_list_item_notation = match field_type {
-            FieldType::List(_) => "[]",
-            FieldType::One(_) => "",
-        };
-        let field_type = match field_type {
-            FieldType::One(data_type) => self.get_field_type(data_type),
-            FieldType::List(data_type) => self.get_field_type(data_type),
-        };
+    fn readonly(&self) -> &'static str {
+        if self.options.typescript.readonly {
+            "readonly "
+        } else {
+            ""
+        }
+    }
 
+    fn convert_field_type(&self, field_type: FieldType) -> super::FieldType {
+        if let Some(mapping) = super::mapped_type(&self.options, &field_type) {
+            return super::FieldType::Normal(mapping.type_expression.clone());
+        }
+        if let Some(known_type) = registry::known_type(&field_type) {
+            return self.convert_known_type(known_type);
+        }
         match field_type {
-            super::FieldType::Normal(specified_type) => super::FieldType::Normal(format!(
-                "{}{}",
-                specified_type, single_or_list_item_notation
-            )),
-            super::FieldType::Embedded => super::FieldType::Embedded,
+            FieldType::Named(data_type) => self.get_field_type(data_type),
+            // Types of other packages aren't declared in the output.
+            FieldType::Qualified(..) => super::FieldType::Normal("unknown".to_string()),
+            FieldType::Slice(element_type) | FieldType::Array(_, element_type) => {
+                self.convert_list_type(*element_type)
+            }
+            FieldType::Pointer(pointee) => self.convert_pointer_type(*pointee),
+            FieldType::Map(key_type, value_type) => self.convert_map_type(*key_type, *value_type),
+            FieldType::Instantiated(generic_type, type_arguments) => {
+                self.convert_instantiated_type(*generic_type, type_arguments)
+            }
+            FieldType::Struct(declaration) => {
+                // Anonymous structs can't extend, they intersect with their embedded types.
+                let mut intersection = super::extended_types(&self.options, &declaration.body);
+                intersection.push(self.interpret_struct_body(declaration.body));
+                super::FieldType::Normal(intersection.join(" & "))
+            }
+        }
+    }
+
+    fn convert_known_type(&self, known_type: &KnownType) -> super::FieldType {
+        if known_type.nullable {
+            super::FieldType::Normal(format!("{} | null", known_type.typescript))
+        } else {
+            super::FieldType::Normal(known_type.typescript.to_string())
+        }
+    }
+
+    fn convert_pointer_type(&self, pointee: FieldType) -> super::FieldType {
+        if super::is_nullable(&pointee) {
+            return self.convert_field_type(pointee);
+        }
+        self.convert_field_type(pointee)
+            .map(|pointee| format!("{} | null", pointee))
+    }
+
+    fn convert_list_type(&self, element_type: FieldType) -> super::FieldType {
+        let needs_wrapping = super::is_compound(&element_type);
+        self.convert_field_type(element_type).map(|element| {
+            if needs_wrapping {
+                format!("Array<{}>", element)
+            } else {
+                format!("{}[]", element)
+            }
+        })
+    }
+
+    fn convert_instantiated_type(
+        &self,
+        generic_type: FieldType,
+        type_arguments: Vec<FieldType>,
+    ) -> super::FieldType {
+        let type_arguments: Vec<String> = type_arguments
+            .into_iter()
+            .filter_map(
+                |type_argument| match self.convert_field_type(type_argument) {
+                    super::FieldType::Normal(type_argument) => Some(type_argument),
+                    super::FieldType::Embedded => None,
+                },
+            )
+            .collect();
+        self.convert_field_type(generic_type)
+            .map(|generic_type| format!("{}<{}>", generic_type, type_arguments.join(", ")))
+    }
+
+    fn convert_map_type(&self, key_type: FieldType, value_type: FieldType) -> super::FieldType {
+        self.convert_key_type(key_type).and_then(|key| {
+            self.convert_field_type(value_type)
+                .map(|value| format!("Record<{}, {}>", key, value))
+        })
+    }
+
+    /// `Record` keys must be strings or numbers, encoding/json writes the rest as strings.
+    fn convert_key_type(&self, key_type: FieldType) -> super::FieldType {
+        let is_string = match &key_type {
+            FieldType::Named(DataType::LargeNumber { .. }) => {
+                self.options.large_integers == LargeIntegerStyle::BigInt
+            }
+            FieldType::Qualified(..) => {
+                super::mapped_type(&self.options, &key_type).is_none()
+                    && registry::known_type(&key_type).is_none()
+            }
+            _ => false,
+        };
+        if is_string {
+            super::FieldType::Normal("string".to_string())
+        } else {
+            self.convert_field_type(key_type)
         }
     }
 
@@ -94,17 +342,29 @@ impl TypeScriptInterpreter {
         field_type: FieldType,
         tags: std::collections::HashMap<crate::ast::TagKey, crate::ast::TagValue>,
     ) -> String {
-        let mut field_name = field_name.0;
-        let field_type = self.convert_field_type(field_type);
-
-        for (key, value) in &tags {
-            if *key == TagKey("json".to_string()) {
-                field_name = value.0.clone()
-            }
+        let json_tag = JsonTag::from_tags(&tags);
+        if json_tag.skip {
+            return String::new();
         }
+        let field_type = if json_tag.as_string {
+            super::quoted_type(field_type)
+        } else {
+            field_type
+        };
+        let field_name = json_tag.name.unwrap_or(field_name.0);
+        let field_name = super::property_name(
+            &self.options,
+            &self.printer(),
+            field_name,
+            &field_type,
+            json_tag.omit_empty,
+        );
+        let field_type = self.convert_field_type(field_type);
         match field_type {
-            super::FieldType::Normal(field_type) => format!("{} : {}, ", field_name, field_type),
-            super::FieldType::Embedded => format!("...{}, ", field_name), // TODO: find out later if its possible to have embedded fields with with JSON tags
+            super::FieldType::Normal(field_type) => {
+                format!("{}{}: {}", self.readonly(), field_name, field_type)
+            }
+            super::FieldType::Embedded => String::new(),
         }
     }
 }
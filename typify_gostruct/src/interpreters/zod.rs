@@ -0,0 +1,142 @@
+use crate::ast::{DataType, AST};
+
+use super::registry::JsonKind;
+use super::{Interpreter, InterpreterError, LargeIntegerStyle, Options, RuntimeValidators, Scope};
+
+pub struct ZodInterpreter {
+    options: Options,
+}
+
+impl Interpreter for ZodInterpreter {
+    fn interpret(&self, ast: Vec<AST>) -> Result<String, InterpreterError> {
+        self.interpret_validators(ast)
+    }
+}
+
+impl ZodInterpreter {
+    pub fn new(options: Options) -> Self {
+        Self { options }
+    }
+}
+
+impl RuntimeValidators for ZodInterpreter {
+    fn options(&self) -> &Options {
+        &self.options
+    }
+
+    fn library(&self) -> (&'static str, &'static str) {
+        ("{ z }", "zod")
+    }
+
+    fn validator_name(&self, name: &str) -> String {
+        format!("{}Schema", name)
+    }
+
+    fn validator_type(&self, type_expression: &str) -> String {
+        format!("z.ZodType<{}>", type_expression)
+    }
+
+    fn inferred_type(&self, constant: &str) -> String {
+        format!("z.infer<typeof {}>", constant)
+    }
+
+    fn type_parameter_bound(&self) -> &'static str {
+        "z.ZodTypeAny"
+    }
+
+    fn lazy(&self, _name: &str, schema: String) -> String {
+        format!("z.lazy(() =>\n{},\n)", self.printer().indent(&schema))
+    }
+
+    fn data_type(&self, data_type: DataType, scope: &Scope) -> Option<String> {
+        let schema = match data_type {
+            DataType::Integer { .. } | DataType::Byte => "z.number().int()".to_string(),
+            DataType::Float => "z.number()".to_string(),
+            DataType::LargeNumber { .. } => match self.options.large_integers {
+                LargeIntegerStyle::Number => "z.number().int()".to_string(),
+                LargeIntegerStyle::String => "z.string()".to_string(),
+                LargeIntegerStyle::BigInt => "z.bigint()".to_string(),
+            },
+            DataType::Complex => "z.never()".to_string(),
+            DataType::String => "z.string()".to_string(),
+            DataType::Boolean => "z.boolean()".to_string(),
+            DataType::Custom(name) => self.reference(name, scope),
+            DataType::Embedded => return None,
+        };
+        Some(schema)
+    }
+
+    fn known_type(&self, json: JsonKind, format: Option<&str>) -> String {
+        let mut schema = match json {
+            JsonKind::Any => "z.unknown()".to_string(),
+            JsonKind::String => "z.string()".to_string(),
+            JsonKind::Number => "z.number()".to_string(),
+            JsonKind::Integer => "z.number().int()".to_string(),
+            JsonKind::Boolean => "z.boolean()".to_string(),
+        };
+        match format {
+            // time.Time marshals to RFC 3339, which has an offset unless it's in UTC.
+            Some("date-time") => schema.push_str(".datetime({ offset: true })"),
+            Some("uuid") => schema.push_str(".uuid()"),
+            _ => {}
+        }
+        schema
+    }
+
+    fn mapped_type(&self, type_expression: &str) -> String {
+        format!("z.custom<{}>()", type_expression)
+    }
+
+    fn unknown(&self) -> String {
+        "z.unknown()".to_string()
+    }
+
+    fn array(&self, element: String, length: Option<usize>) -> String {
+        match length {
+            Some(length) => format!("z.array({}).length({})", element, length),
+            None => format!("z.array({})", element),
+        }
+    }
+
+    fn nullable(&self, schema: String) -> String {
+        format!("{}.nullable()", schema)
+    }
+
+    fn record(&self, value: String) -> String {
+        format!("z.record(z.string(), {})", value)
+    }
+
+    fn object(&self, properties: Vec<(String, bool)>, extended: bool) -> String {
+        let properties: Vec<String> = properties
+            .into_iter()
+            .map(|(property, optional)| {
+                if optional {
+                    format!("{}.optional()", property)
+                } else {
+                    property
+                }
+            })
+            .collect();
+        let schema = format!("z.object({})", self.printer().object_literal(properties));
+        if extended {
+            // Keeps the fields of the embedded types that aren't declared in the input.
+            format!("{}.passthrough()", schema)
+        } else {
+            schema
+        }
+    }
+
+    fn enumeration(&self, literals: Vec<String>, strings: bool) -> String {
+        if strings {
+            return format!("z.enum([{}])", literals.join(", "));
+        }
+        let literals: Vec<String> = literals
+            .into_iter()
+            .map(|literal| format!("z.literal({})", literal))
+            .collect();
+        match &literals[..] {
+            [literal] => literal.clone(),
+            literals => format!("z.union([{}])", literals.join(", ")),
+        }
+    }
+}
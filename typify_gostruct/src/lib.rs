@@ -3,13 +3,22 @@
 use interpreters::ToInterpreter;
 use scanner::Input;
 
+pub use interpreters::{
+    DeclarationStyle, EnumStyle, ExportStyle, FlowOptions, Formatting, Indent, LargeIntegerStyle,
+    ObjectStyle, Options, QuoteStyle, Separator, TypeMapping, TypeScriptOptions,
+};
+
 mod ast;
 mod interpreters;
 mod parser;
+mod resolver;
 mod scanner;
 
 /// The source holds the go-lang struct(s) that you want to transform
-pub struct Source<S>(S);
+pub struct Source<S> {
+    input: S,
+    options: Options,
+}
 
 impl<I> Source<I>
 where
@@ -32,7 +41,35 @@ where
     ///
     /// ```
     pub fn new(input: I) -> Self {
-        Self(input)
+        Self {
+            input,
+            options: Options::default(),
+        }
+    }
+    /// Returns the Source with options that tweak the output of the interpreters.
+    /// # Arguments
+    ///
+    /// * `options` - The `Options` used by every subsequent call to `transform_to`.
+    ///
+    /// # Examples
+    /// ```
+    /// use typify_gostruct::{EnumStyle, Options};
+    /// let input = r#"
+    /// type Role string
+    /// const (
+    /// RoleAdmin Role = "admin"
+    /// RoleUser Role = "user"
+    /// )"#;
+    /// let mut options = Options::default();
+    /// options.typescript.enums = EnumStyle::Enum;
+    /// let source = typify_gostruct::Source::new(input).with_options(options);
+    /// let result = source.transform_to("typescript").expect("The enum should be transformed without an issue");
+    /// assert!(result.contains("export enum Role"))
+    ///
+    /// ```
+    pub fn with_options(mut self, options: Options) -> Self {
+        self.options = options;
+        self
     }
     /// Returns the transformed go-lang struct.
     /// # Arguments
@@ -40,7 +77,7 @@ where
     /// * `target` - Target represents the target interpreter that will be used for the transformation. This can be anything that implements the `TargetIntepreter` Trait. By default this can be a `String` or `&str`
     ///
     ///
-    /// * #### N/B: The targets that can be used as of now are, "flow" & "typescript" (more to come...).
+    /// * #### N/B: The targets that can be used as of now are, "flow", "typescript", "jsonschema", "zod", "io-ts" & "rust" (more to come...).
     /// # Examples
     /// ```
     /// use typify_gostruct;
@@ -58,13 +95,62 @@ where
     where
         T: ToInterpreter,
     {
-        let tokens = scanner::scan(self.0)?;
-        let parsed_result = parser::parse(&tokens)?;
-        let interpreter = target.convert()?;
+        let tokens = scanner::scan(self.input)?;
+        let interpreter = target.convert_with(&self.options)?;
+        let parsed_result = resolver::resolve(
+            parser::parse(&tokens)?,
+            &self.options,
+            interpreter.flattens_embedded_structs(),
+        );
         interpreter
             .interpret(parsed_result)
             .map_err(|err| err.into())
     }
+    /// Returns the transformation of every declaration that parsed cleanly, along with the
+    /// errors found in the rest of the input instead of stopping at the first one.
+    /// # Arguments
+    ///
+    /// * `target` - The target interpreter, same as for `transform_to`.
+    ///
+    /// # Examples
+    /// ```
+    /// use typify_gostruct;
+    /// let input = r#"
+    /// type Region struct {
+    /// Country string `json:country`
+    /// }
+    /// type City struct {
+    /// Name string
+    /// }"#;
+    /// let source = typify_gostruct::Source::new(input);
+    /// let transformed = source.transform_partially_to("typescript").expect("The target should be known");
+    /// assert!(transformed.output.contains("export interface City"));
+    /// assert_eq!(transformed.errors.len(), 1)
+    ///
+    /// ```
+    pub fn transform_partially_to<T>(&self, target: T) -> Result<PartialTransform, Vec<String>>
+    where
+        T: ToInterpreter,
+    {
+        let tokens = scanner::scan(self.input)?;
+        let (parsed_result, errors) = parser::parse_partially(&tokens);
+        let interpreter = target.convert_with(&self.options)?;
+        let parsed_result = resolver::resolve(
+            parsed_result,
+            &self.options,
+            interpreter.flattens_embedded_structs(),
+        );
+        let output = interpreter.interpret(parsed_result)?;
+        Ok(PartialTransform { output, errors })
+    }
+}
+
+/// The outcome of `Source::transform_partially_to`.
+pub struct PartialTransform {
+    /// The transformed declarations that parsed cleanly.
+    pub output: String,
+    /// Every parse error found in the input.
+    pub errors: Vec<String>,
 }
 
 mod tests {
@@ -81,8 +167,8 @@ mod tests {
             .transform_to("flow")
             .expect("The struct should be transformed without an issue");
         assert!(result.contains("// @flow"));
-        assert!(result.contains("country : string"));
-        assert!(result.contains("State : string"))
+        assert!(result.contains("country: string"));
+        assert!(result.contains("State: string"))
     }
 
     #[test]
@@ -97,8 +183,8 @@ mod tests {
             .transform_to("typescript")
             .expect("The struct should be transformed without an issue");
         assert!(result.contains("export interface Region"));
-        assert!(result.contains("country : string"));
-        assert!(result.contains("State : string"))
+        assert!(result.contains("country: string"));
+        assert!(result.contains("State: string"))
     }
 
     #[test]
@@ -127,4 +213,1044 @@ mod tests {
         let result = source.transform_to("typescript");
         assert!(result.is_ok())
     }
+
+    #[test]
+    fn should_transform_map_fields_successfully() {
+        let input = r#"
+        type Catalog struct {
+        Labels map[string]string `json:"labels"`
+        Items map[string][]Item
+    }
+    "#;
+        let source = super::Source::new(input);
+        let result = source
+            .transform_to("typescript")
+            .expect("The struct should be transformed without an issue");
+        assert!(result.contains("labels: Record<string, string>"));
+        assert!(result.contains("Items: Record<string, Item[]>"));
+
+        let result = source
+            .transform_to("flow")
+            .expect("The struct should be transformed without an issue");
+        assert!(result.contains("labels: { [key: string]: string }"));
+        assert!(result.contains("Items: { [key: string]: Item[] }"))
+    }
+
+    #[test]
+    fn should_transform_nested_type_expressions_successfully() {
+        let input = r#"
+        type Matrix struct {
+        Rows [][]string `json:"rows"`
+        Groups []map[string][]int `json:"groups"`
+        Owner *[]*Foo
+        Checksum [4]byte
+    }
+    "#;
+        let source = super::Source::new(input);
+        let result = source
+            .transform_to("typescript")
+            .expect("The struct should be transformed without an issue");
+        assert!(result.contains("rows: string[][]"));
+        assert!(result.contains("groups: Array<Record<string, number[]>>"));
+        assert!(result.contains("Owner: Array<Foo | null> | null"));
+
+        let result = source
+            .transform_to("flow")
+            .expect("The struct should be transformed without an issue");
+        assert!(result.contains("rows: string[][]"));
+        assert!(result.contains("groups: Array<{ [key: string]: number[] }>"));
+        assert!(result.contains("Owner: ?Array<?Foo>"))
+    }
+
+    #[test]
+    fn should_transform_anonymous_structs_successfully() {
+        let input = r#"
+        type Response struct {
+        Meta struct { Page int; Total int } `json:"meta"`
+        Items []struct {
+            ID string `json:"id"`
+        } `json:"items"`
+    }
+    "#;
+        let source = super::Source::new(input);
+        let result = source
+            .transform_to("typescript")
+            .expect("The struct should be transformed without an issue");
+        assert!(result.contains("meta: {\n    Page: number;\n    Total: number;\n  };"));
+        assert!(result.contains("items: Array<{\n    id: string;\n  }>;"));
+
+        let result = source
+            .transform_to("flow")
+            .expect("The struct should be transformed without an issue");
+        assert!(result.contains("meta: {\n    Page: number,\n    Total: number,\n  },"))
+    }
+
+    #[test]
+    fn should_transform_defined_types_and_aliases_successfully() {
+        let input = r#"
+        type Status string
+        type IDs = []int64
+        type Account struct {
+        Status Status `json:"status"`
+    }
+    "#;
+        let source = super::Source::new(input);
+        let result = source
+            .transform_to("typescript")
+            .expect("The types should be transformed without an issue");
+        assert!(result.contains("export type Status = string"));
+        assert!(result.contains("export type IDs = number[]"));
+        assert!(result.contains("status: Status"));
+
+        let result = source
+            .transform_to("flow")
+            .expect("The types should be transformed without an issue");
+        assert!(result.contains("export type Status = string"));
+        assert!(result.contains("export type IDs = number[]"))
+    }
+
+    #[test]
+    fn should_transform_constants_to_unions_and_enums_successfully() {
+        let input = r#"
+        type Role string
+        const (
+            RoleAdmin Role = "admin"
+            RoleUser  Role = "user"
+        )
+        type Level int
+        const (
+            Low Level = iota + 1
+            High
+        )
+    "#;
+        let source = super::Source::new(input);
+        let result = source
+            .transform_to("typescript")
+            .expect("The types should be transformed without an issue");
+        assert!(result.contains(r#"export type Role = "admin" | "user""#));
+        assert!(result.contains("export type Level = 1 | 2"));
+
+        let result = source
+            .transform_to("flow")
+            .expect("The types should be transformed without an issue");
+        assert!(result.contains(r#"export type Role = "admin" | "user""#));
+
+        let mut options = super::Options::default();
+        options.typescript.enums = super::EnumStyle::Enum;
+        let result = super::Source::new(input)
+            .with_options(options)
+            .transform_to("typescript")
+            .expect("The types should be transformed without an issue");
+        assert!(result
+            .contains("export enum Role {\n  RoleAdmin = \"admin\",\n  RoleUser = \"user\",\n}"));
+        assert!(result.contains("export enum Level {\n  Low = 1,\n  High = 2,\n}"))
+    }
+
+    #[test]
+    fn should_transform_raw_string_constants_successfully() {
+        let input = r#"
+        type Pattern string
+        const (
+            Digits Pattern = `\d+`
+            Quoted Pattern = `"quoted"`
+        )
+    "#;
+        let result = super::Source::new(input)
+            .transform_to("typescript")
+            .expect("The types should be transformed without an issue");
+        assert!(result.contains(r#"export type Pattern = "\\d+" | "\"quoted\"""#));
+    }
+
+    #[test]
+    fn should_transform_generic_structs_successfully() {
+        let input = r#"
+        type Page[T any] struct {
+        Items []T `json:"items"`
+        Next *string `json:"next"`
+    }
+        type Pair[K comparable, V ~int | ~float64] struct {
+        Key K
+        Value V
+    }
+        type Listing struct {
+        Users Page[User] `json:"users"`
+    }
+    "#;
+        let source = super::Source::new(input);
+        let result = source
+            .transform_to("typescript")
+            .expect("The structs should be transformed without an issue");
+        assert!(result.contains("export interface Page<T>"));
+        assert!(result.contains("items: T[]"));
+        assert!(result.contains("export interface Pair<K, V extends number>"));
+        assert!(result.contains("users: Page<User>"));
+
+        let result = source
+            .transform_to("flow")
+            .expect("The structs should be transformed without an issue");
+        assert!(result.contains("export type Page<T> = "));
+        assert!(result.contains("export type Pair<K, V: number> = "));
+        assert!(result.contains("users: Page<User>"))
+    }
+
+    #[test]
+    fn should_transform_commented_structs_successfully() {
+        let input = r#"
+        // Region is where an account lives.
+        type Region struct {
+        /* The ISO country code */
+        Country string `json:"country"` // trailing comments are dropped
+        // State is optional.
+        // It's empty outside of the US.
+        State string
+    }
+    "#;
+        let source = super::Source::new(input);
+        let result = source
+            .transform_to("typescript")
+            .expect("The struct should be transformed without an issue");
+        assert!(
+            result.contains("/** Region is where an account lives. */\nexport interface Region")
+        );
+        assert!(result.contains("  /** The ISO country code */\n  country: string;"));
+        assert!(result.contains(
+            "  /**\n   * State is optional.\n   * It's empty outside of the US.\n   */\n  State: string;"
+        ));
+        assert!(!result.contains("trailing"));
+
+        let result = source
+            .transform_to("flow")
+            .expect("The struct should be transformed without an issue");
+        assert!(result.contains("/** Region is where an account lives. */\nexport type Region"));
+        assert!(result.contains("  /** The ISO country code */\n  country: string,"))
+    }
+
+    #[test]
+    fn should_transform_whole_go_source_file_successfully() {
+        let input = r#"
+package models
+
+import (
+    "fmt"
+    "time"
+)
+
+var ErrMissingCountry = fmt.Errorf("missing country")
+
+type Region struct {
+    Country   string `json:"country"`
+    CreatedAt time.Time
+}
+
+func (r Region) Validate() error {
+    if r.Country == "" {
+        return ErrMissingCountry
+    }
+    return nil
+}
+"#;
+        let source = super::Source::new(input);
+        let result = source
+            .transform_to("typescript")
+            .expect("The source file should be transformed without an issue");
+        assert!(result.contains("export interface Region"));
+        assert!(result.contains("country: string"));
+        assert!(!result.contains("Validate"))
+    }
+
+    #[test]
+    fn should_transform_partially_when_some_structs_are_invalid() {
+        let input = r#"
+        type Region struct {
+            Country string `json:country`
+        }
+
+        type City struct {
+            Name string
+        }
+
+        type Street struct {
+            Name string `json:`
+        }
+        "#;
+        let source = super::Source::new(input);
+        assert_eq!(
+            source
+                .transform_to("typescript")
+                .expect_err("The invalid structs should fail")
+                .len(),
+            2
+        );
+        let transformed = source
+            .transform_partially_to("typescript")
+            .expect("The valid struct should be transformed without an issue");
+        assert!(transformed.output.contains("export interface City"));
+        assert!(!transformed.output.contains("Region"));
+        assert_eq!(transformed.errors.len(), 2)
+    }
+
+    #[test]
+    fn should_transform_every_predeclared_type_successfully() {
+        let input = r#"
+        type Sample struct {
+            Small int8
+            Unsigned uint16
+            Ratio float32
+            Letter rune
+            Raw byte
+            Address uintptr
+            ID int64
+            Count uint64
+            Signal complex128
+        }"#;
+        let source = super::Source::new(input);
+        let result = source
+            .transform_to("typescript")
+            .expect("The struct should be transformed without an issue");
+        for field in [
+            "Small", "Unsigned", "Ratio", "Letter", "Raw", "Address", "ID", "Count",
+        ] {
+            assert!(result.contains(&format!("{}: number", field)));
+        }
+        assert!(result.contains("Signal: never"));
+
+        let options = super::Options {
+            large_integers: super::LargeIntegerStyle::BigInt,
+            ..Default::default()
+        };
+        let source = super::Source::new(input).with_options(options);
+        let result = source
+            .transform_to("flow")
+            .expect("The struct should be transformed without an issue");
+        assert!(result.contains("Small: number"));
+        assert!(result.contains("ID: bigint"));
+        assert!(result.contains("Count: bigint"));
+        assert!(result.contains("Signal: empty"))
+    }
+
+    #[test]
+    fn should_transform_well_known_types_successfully() {
+        let input = r#"
+        type Account struct {
+            ID uuid.UUID
+            CreatedAt time.Time
+            Timeout time.Duration
+            Avatar []byte
+            Balance decimal.Decimal
+            Nickname sql.NullString
+            Aliases []sql.NullString
+            Settings json.RawMessage
+            Extra interface{}
+            Labels map[string]any
+            Ratio big.Rat
+            Total big.Float
+            Failure error
+        }"#;
+        let source = super::Source::new(input);
+        let result = source
+            .transform_to("typescript")
+            .expect("The struct should be transformed without an issue");
+        assert!(result.contains("ID: string"));
+        assert!(result.contains("CreatedAt: string"));
+        assert!(result.contains("Timeout: number"));
+        assert!(result.contains("Avatar: string"));
+        assert!(result.contains("Balance: string"));
+        assert!(result.contains("Nickname: string | null"));
+        assert!(result.contains("Aliases: Array<string | null>"));
+        assert!(result.contains("Settings: unknown"));
+        assert!(result.contains("Extra: unknown"));
+        assert!(result.contains("Labels: Record<string, unknown>"));
+        assert!(result.contains("Ratio: string"));
+        assert!(result.contains("Total: string"));
+        assert!(result.contains("Failure: unknown"));
+
+        let result = source
+            .transform_to("flow")
+            .expect("The struct should be transformed without an issue");
+        assert!(result.contains("Avatar: string"));
+        assert!(result.contains("Nickname: ?string"));
+        assert!(result.contains("Aliases: Array<?string>"));
+        assert!(result.contains("Extra: mixed"));
+        assert!(result.contains("Failure: mixed"))
+    }
+
+    #[test]
+    fn should_transform_with_custom_type_mappings_successfully() {
+        let input = r#"
+        type Invoice struct {
+            Tenant ids.TenantID
+            Total money.Amount
+            Lines []money.Amount
+            Owner UserID
+            Issued time.Time
+        }"#;
+        let import = r#"import type { Amount } from "@acme/money";"#;
+        let options = super::Options::default()
+            .with_type_mapping(
+                "money.Amount",
+                super::TypeMapping::new("Amount").with_import(import),
+            )
+            .with_type_mapping("ids.TenantID", super::TypeMapping::new("string"))
+            .with_type_mapping("UserID", super::TypeMapping::new("number"))
+            .with_type_mapping("time.Time", super::TypeMapping::new("Date"));
+        let source = super::Source::new(input).with_options(options);
+        let result = source
+            .transform_to("typescript")
+            .expect("The struct should be transformed without an issue");
+        assert!(result.starts_with(&format!("{}\n", import)));
+        assert_eq!(result.matches(import).count(), 1);
+        assert!(result.contains("Tenant: string"));
+        assert!(result.contains("Total: Amount"));
+        assert!(result.contains("Lines: Amount[]"));
+        assert!(result.contains("Owner: number"));
+        assert!(result.contains("Issued: Date"));
+
+        let result = source
+            .transform_to("flow")
+            .expect("The struct should be transformed without an issue");
+        assert!(result.starts_with(&format!("// @flow\n\n{}\n\n", import)));
+        assert!(result.contains("Total: Amount"));
+
+        let result = source
+            .transform_to("io-ts")
+            .expect("The struct should be transformed without an issue");
+        assert!(result.starts_with(&format!("import * as t from \"io-ts\";\n{}\n\n", import)));
+        assert!(result.contains("Total: t.unknown as t.Mixed as t.Type<Amount>,"));
+        assert!(result.contains("Lines: t.array(t.unknown as t.Mixed as t.Type<Amount>),"))
+    }
+
+    #[test]
+    fn should_transform_pointers_to_nullable_types_successfully() {
+        let input = r#"
+        type Profile struct {
+            Name string
+            Nickname *string `json:"nickname"`
+            Parent *Profile
+            Note *sql.NullString
+        }"#;
+        let source = super::Source::new(input);
+        let result = source
+            .transform_to("typescript")
+            .expect("The struct should be transformed without an issue");
+        assert!(result.contains("Name: string"));
+        assert!(result.contains("nickname: string | null"));
+        assert!(result.contains("Parent: Profile | null"));
+        assert!(result.contains("Note: string | null;\n}"));
+
+        let result = source
+            .transform_to("flow")
+            .expect("The struct should be transformed without an issue");
+        assert!(result.contains("nickname: ?string"));
+        assert!(result.contains("Parent: ?Profile"));
+
+        let options = super::Options {
+            optional_pointers: true,
+            ..Default::default()
+        };
+        let source = super::Source::new(input).with_options(options);
+        let result = source
+            .transform_to("typescript")
+            .expect("The struct should be transformed without an issue");
+        assert!(result.contains("Name: string"));
+        assert!(result.contains("nickname?: string | null"));
+        assert!(result.contains("Parent?: Profile | null"));
+
+        let result = source
+            .transform_to("flow")
+            .expect("The struct should be transformed without an issue");
+        assert!(result.contains("nickname?: ?string"))
+    }
+
+    #[test]
+    fn should_honour_json_tag_options_successfully() {
+        let input = r#"
+        type User struct {
+            Name string `json:"name,omitempty"`
+            Password string `json:"-"`
+            // Dash is literally called "-".
+            Dash string `json:"-,"`
+            Email string `json:",omitempty"`
+            ID int64 `json:"id,string"`
+            Active *bool `json:"active,string,omitempty"`
+            // Secret is never sent.
+            Secret string `json:"-"`
+        }"#;
+        let source = super::Source::new(input);
+        let result = source
+            .transform_to("typescript")
+            .expect("The struct should be transformed without an issue");
+        assert!(result.contains("name?: string"));
+        assert!(!result.contains("Password"));
+        assert!(result.contains(r#""-": string"#));
+        assert!(result.contains("Email?: string"));
+        assert!(result.contains("id: string"));
+        assert!(result.contains("active?: string | null"));
+        assert!(!result.contains("Secret"));
+        assert!(!result.contains("omitempty"));
+
+        let result = source
+            .transform_to("flow")
+            .expect("The struct should be transformed without an issue");
+        assert!(result.contains("name?: string"));
+        assert!(!result.contains("Password"));
+        assert!(result.contains("active?: ?string"))
+    }
+
+    #[test]
+    fn should_follow_go_json_visibility_rules_successfully() {
+        let input = r#"
+        type timestamps struct {
+            CreatedAt string
+        }
+
+        type Named struct {
+            Name string
+            Label string
+        }
+
+        type Labeled struct {
+            Label string
+            Title string `json:"Title"`
+        }
+
+        type Tagged struct {
+            Title int
+        }
+
+        type status string
+
+        type Account struct {
+            timestamps
+            status
+            Named
+            Labeled
+            Tagged
+            ID string
+            password string
+            Name int
+        }"#;
+        let source = super::Source::new(input);
+        let result = source
+            .transform_to("typescript")
+            .expect("The structs should be transformed without an issue");
+        let account = &result[result
+            .find("export interface Account")
+            .expect("Account should be transformed")..];
+        assert!(account.contains("CreatedAt: string"));
+        assert!(account.contains("ID: string"));
+        assert!(!account.contains("password"));
+        assert!(!account.contains("status"));
+        assert!(!account.contains("Label"));
+        assert!(account.contains("Title: string"));
+        assert!(!account.contains("Title: number"));
+        assert!(account.contains("Name: number"));
+        assert!(!account.contains("Name: string"));
+        assert!(!account.contains("..."));
+
+        let options = super::Options {
+            keep_unexported_fields: true,
+            ..Default::default()
+        };
+        let source = super::Source::new(input).with_options(options);
+        let result = source
+            .transform_to("flow")
+            .expect("The structs should be transformed without an issue");
+        assert!(result.contains("password: string"));
+        assert!(result.contains("status: status"))
+    }
+
+    #[test]
+    fn should_resolve_embedded_structs_successfully() {
+        let input = r#"
+        type Status string
+
+        type Base struct {
+            ID string `json:"id"`
+        }
+
+        type Account struct {
+            Base
+            Status
+            gorm.Model
+            *Owner
+            Name string
+            Settings struct {
+                Defaults
+                Theme string
+            }
+        }"#;
+        let source = super::Source::new(input);
+        let result = source
+            .transform_to("typescript")
+            .expect("The structs should be transformed without an issue");
+        // Types of other packages aren't declared, so there's nothing to extend.
+        assert!(result.contains("export interface Account extends Owner {"));
+        assert!(result.contains("{\n  id: string;\n  Status: Status;\n  Name: string;\n  Settings"));
+        assert!(result.contains("Settings: Defaults & {\n    Theme: string;\n  };"));
+        assert!(!result.contains("..."));
+
+        let result = source
+            .transform_to("flow")
+            .expect("The structs should be transformed without an issue");
+        assert!(result.contains(
+            "export type Account = {\n  ...Owner,\n  id: string,\n  Status: Status,\n  Name: string,"
+        ));
+        assert!(result.contains("Settings: {\n    ...Defaults,\n    Theme: string,\n  },"))
+    }
+
+    #[test]
+    fn should_transform_with_typescript_styles_successfully() {
+        let input = r#"
+        type Base struct {
+            ID string `json:"id"`
+        }
+
+        type Region struct {
+            Base
+            Trail
+            Country string `json:"country"`
+            Meta struct { Page int }
+        }
+
+        type Status string"#;
+        let options = super::Options {
+            typescript: super::TypeScriptOptions {
+                declarations: super::DeclarationStyle::TypeAlias,
+                exports: super::ExportStyle::Default,
+                readonly: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let source = super::Source::new(input).with_options(options);
+        let result = source
+            .transform_to("typescript")
+            .expect("The structs should be transformed without an issue");
+        assert!(result.contains("export type Base = {\n  readonly id: string;\n};"));
+        assert!(result.contains(
+            "export type Region = Trail & {\n  readonly id: string;\n  readonly country: string;\n  readonly Meta: {\n    readonly Page: number;\n  };\n};"
+        ));
+        assert!(result.contains("export type Status = string;"));
+        assert!(result.ends_with("export default Base;\n"));
+
+        let options = super::Options {
+            typescript: super::TypeScriptOptions {
+                declarations: super::DeclarationStyle::Declare,
+                exports: super::ExportStyle::None,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let source = super::Source::new(input).with_options(options);
+        let result = source
+            .transform_to("typescript")
+            .expect("The structs should be transformed without an issue");
+        assert!(result.contains("declare interface Region extends Trail {\n  id: string;"));
+        assert!(result.contains("declare type Status = string;"));
+        assert!(!result.contains("export"))
+    }
+
+    #[test]
+    fn should_reencode_go_escapes_in_string_literals() {
+        let input = r#"
+        type Greeting string
+        const (
+            Bell Greeting = "\a\101\x41\u00e9"
+            Line Greeting = `one
+two`
+        )"#;
+        let result = super::Source::new(input)
+            .transform_to("typescript")
+            .expect("The types should be transformed without an issue");
+        assert!(result.contains(r#"export type Greeting = "\u0007AAé" | "one\ntwo";"#));
+    }
+
+    #[test]
+    fn should_transform_with_flow_styles_successfully() {
+        let input = r#"
+        type Trail struct {
+            By string
+        }
+
+        type Region struct {
+            Trail
+            Country string `json:"country"`
+            Codes []string
+            Parent *Region
+            Labels map[string]string
+        }"#;
+        let options = super::Options {
+            flow: super::FlowOptions {
+                objects: super::ObjectStyle::Exact,
+                readonly: true,
+                readonly_arrays: true,
+            },
+            ..Default::default()
+        };
+        let result = super::Source::new(input)
+            .with_options(options)
+            .transform_to("flow")
+            .expect("The struct should be transformed without an issue");
+        assert!(result.contains(
+            "export type Region = {|\n  +By: string,\n  +country: string,\n  +Codes: $ReadOnlyArray<string>,\n  +Parent: ?Region,\n  +Labels: { +[key: string]: string },\n|};"
+        ));
+
+        let result = super::Source::new(input)
+            .transform_to("flow")
+            .expect("The struct should be transformed without an issue");
+        assert!(result.contains("export type Region = {\n  By: string,\n  country: string,"));
+        assert!(result.contains("Codes: string[],"))
+    }
+
+    #[test]
+    fn should_transform_to_json_schema_successfully() {
+        let input = r#"
+        type Status string
+        const (
+            Active Status = "active"
+            Closed Status = "closed"
+        )
+
+        // Account is a customer.
+        type Account struct {
+            ID string `json:"id"`
+            Name *string `json:"name,omitempty" binding:"required,min=1"`
+            Nickname *string `json:"nickname,omitempty"`
+            Status Status `json:"status"`
+            Parent *Account `json:"parent"`
+            Codes [2]string
+            Labels map[string]int
+            audit.Trail
+        }"#;
+        let result = super::Source::new(input)
+            .transform_to("jsonschema")
+            .expect("The types should be transformed without an issue");
+        assert!(result.starts_with(
+            "{\n  \"$schema\": \"https://json-schema.org/draft/2020-12/schema\",\n  \"$defs\": {"
+        ));
+        assert!(result.contains(
+            "\"Status\": {\n      \"type\": \"string\",\n      \"enum\": [\"active\", \"closed\"]\n    },"
+        ));
+        assert!(result.contains(
+            "\"Account\": {\n      \"description\": \"Account is a customer.\",\n      \"type\": \"object\","
+        ));
+        assert!(
+            result.contains("\"name\": {\n          \"type\": [\"string\", \"null\"]\n        }")
+        );
+        assert!(result.contains("\"status\": {\n          \"$ref\": \"#/$defs/Status\"\n        }"));
+        assert!(result.contains(
+            "\"anyOf\": [\n            {\n              \"$ref\": \"#/$defs/Account\"\n            },\n            {\n              \"type\": \"null\"\n            }\n          ]"
+        ));
+        assert!(result.contains("\"minItems\": 2,\n          \"maxItems\": 2"));
+        assert!(result.contains(
+            "\"additionalProperties\": {\n            \"type\": \"integer\"\n          }"
+        ));
+        assert!(result.contains(
+            "\"required\": [\"id\", \"name\", \"status\", \"parent\", \"Codes\", \"Labels\"]"
+        ));
+        assert!(!result.contains("Trail"));
+        assert!(result.ends_with("}\n"))
+    }
+
+    #[test]
+    fn should_transform_to_json_schema_with_custom_type_mappings_successfully() {
+        let input = r#"
+        type Greeting string
+        const (
+            Bell Greeting = "\a\101"
+        )
+
+        type Payment struct {
+            Amount money.Amount
+            Note ids.Note
+        }"#;
+        let options = super::Options::default()
+            .with_type_mapping(
+                "money.Amount",
+                super::TypeMapping::new("Amount").with_json_schema(r#"{ "type": "string" }"#),
+            )
+            .with_type_mapping("ids.Note", super::TypeMapping::new("string"));
+        let result = super::Source::new(input)
+            .with_options(options)
+            .transform_to("jsonschema")
+            .expect("The types should be transformed without an issue");
+        assert!(result.contains(r#""enum": ["\u0007A"]"#));
+        assert!(result.contains(r#""Amount": { "type": "string" },"#));
+        assert!(result.contains(r#""Note": {}"#))
+    }
+
+    #[test]
+    fn should_transform_to_zod_schemas_successfully() {
+        let input = r#"
+        type Role string
+        const (
+            Admin Role = "admin"
+            Member Role = "member"
+        )
+
+        // Account is a customer.
+        type Account struct {
+            Name *string `json:"name,omitempty"`
+            Role Role `json:"role"`
+            Category Category `json:"category"`
+            Page Page[Role] `json:"page"`
+        }
+
+        type Category struct {
+            Name string
+            Parent *Category
+        }
+
+        type Page[T any] struct {
+            Items []T `json:"items"`
+        }"#;
+        let result = super::Source::new(input)
+            .transform_to("zod")
+            .expect("The types should be transformed without an issue");
+        assert!(result.starts_with("import { z } from \"zod\";\n\n"));
+        assert!(result.contains(
+            "export const RoleSchema = z.enum([\"admin\", \"member\"]);\nexport type Role = z.infer<typeof RoleSchema>;"
+        ));
+        assert!(result.contains(
+            "export type Category = {\n  Name: string;\n  Parent: Category | null;\n};\nexport const CategorySchema: z.ZodType<Category> = z.lazy(() =>\n  z.object({\n    Name: z.string(),\n    Parent: CategorySchema.nullable(),\n  }),\n);"
+        ));
+        assert!(result.contains(
+            "export const PageSchema = <T extends z.ZodTypeAny>(T: T) => z.object({\n  items: z.array(T),\n});"
+        ));
+        assert!(result.contains(
+            "/** Account is a customer. */\nexport const AccountSchema = z.object({\n  name: z.string().nullable().optional(),\n  role: RoleSchema,\n  category: CategorySchema,\n  page: PageSchema(RoleSchema),\n});\nexport type Account = z.infer<typeof AccountSchema>;"
+        ));
+        // Schemas are declared before they are used.
+        let position = |name: &str| result.find(&format!("export const {}Schema", name));
+        assert!(position("Category") < position("Account"));
+        assert!(position("Page") < position("Account"))
+    }
+
+    #[test]
+    fn should_transform_to_io_ts_codecs_successfully() {
+        let input = r#"
+        type Level int
+        const (
+            Low Level = iota + 1
+            High
+        )
+
+        type Account struct {
+            ID string `json:"id"`
+            Name *string `json:"name,omitempty"`
+            Labels map[string]string `json:"labels"`
+            Level Level `json:"level"`
+            Page Page[Category] `json:"page"`
+        }
+
+        type Settings struct {
+            Theme string `json:"theme,omitempty"`
+        }
+
+        type Category struct {
+            Children []Category
+        }
+
+        type Page[T any] struct {
+            Items []T `json:"items"`
+        }"#;
+        let result = super::Source::new(input)
+            .transform_to("io-ts")
+            .expect("The types should be transformed without an issue");
+        assert!(result.starts_with("import * as t from \"io-ts\";\n\n"));
+        assert!(result.contains(
+            "export const Level = t.union([t.literal(1), t.literal(2)]);\nexport type Level = t.TypeOf<typeof Level>;"
+        ));
+        assert!(result.contains(
+            "export const Account = t.intersection([\n  t.type({\n    id: t.string,\n    labels: t.record(t.string, t.string),\n    level: Level,\n    page: Page(Category),\n  }),\n  t.partial({\n    name: t.union([t.string, t.null]),\n  }),\n]);"
+        ));
+        assert!(result.contains("export const Settings = t.partial({\n  theme: t.string,\n});"));
+        assert!(result.contains(
+            "export type Category = {\n  Children: Category[];\n};\nexport const Category: t.Type<Category> = t.recursion(\"Category\", () =>\n  t.type({\n    Children: t.array(Category),\n  }),\n);"
+        ));
+        assert!(result.contains(
+            "export const Page = <T extends t.Mixed>(T: T) => t.type({\n  items: t.array(T),\n});"
+        ));
+        // Codecs are declared before they are used.
+        let position = |name: &str| result.find(&format!("export const {}", name));
+        assert!(position("Category") < position("Account"));
+        assert!(position("Page") < position("Account"))
+    }
+
+    #[test]
+    fn should_transform_to_rust_structs_successfully() {
+        let input = r#"
+        type Role string
+        const (
+            RoleAdmin Role = "admin"
+            RoleGuest Role = "guest"
+        )
+
+        type Base struct {
+            ID int64 `json:"id"`
+        }
+
+        // Account is a customer.
+        type Account struct {
+            Base
+            Type string `json:"type"`
+            HTTPPort int `json:"http_port,omitempty"`
+            Parent *Account `json:"parent"`
+            Labels map[string]string `json:"labels"`
+            Role Role `json:"role"`
+            Meta struct {
+                Owner *Account `json:"owner"`
+            }
+        }"#;
+        let result = super::Source::new(input)
+            .transform_to("rust")
+            .expect("The types should be transformed without an issue");
+        assert!(result.starts_with(
+            "use serde::{Deserialize, Serialize};\nuse std::collections::HashMap;\n\n"
+        ));
+        assert!(result.contains(
+            "#[derive(Serialize, Deserialize)]\npub enum Role {\n    #[serde(rename = \"admin\")]\n    RoleAdmin,\n    #[serde(rename = \"guest\")]\n    RoleGuest,\n}"
+        ));
+        assert!(result.contains(
+            "/// Account is a customer.\n#[derive(Serialize, Deserialize)]\npub struct Account {\n    #[serde(flatten)]\n    pub base: Base,\n    pub r#type: String,\n    #[serde(skip_serializing_if = \"Option::is_none\")]\n    pub http_port: Option<i64>,\n    pub parent: Option<Box<Account>>,\n    pub labels: HashMap<String, String>,\n    pub role: Role,\n    #[serde(rename = \"Meta\")]\n    pub meta: AccountMeta,\n}"
+        ));
+        // Anonymous structs are declared after the struct using them.
+        assert!(result.ends_with(
+            "#[derive(Serialize, Deserialize)]\npub struct AccountMeta {\n    pub owner: Option<Box<Account>>,\n}\n"
+        ));
+    }
+
+    #[test]
+    fn should_transform_to_rust_with_custom_type_mappings_successfully() {
+        let input = r#"
+        type Greeting string
+        const (
+            Quote Greeting = "say \"hi\"\n"
+        )
+
+        type Payment struct {
+            Amount money.Amount
+            Note ids.Note
+        }"#;
+        let options = super::Options::default()
+            .with_type_mapping(
+                "money.Amount",
+                super::TypeMapping::new("Amount").with_rust("rust_decimal::Decimal"),
+            )
+            .with_type_mapping("ids.Note", super::TypeMapping::new("string"));
+        let result = super::Source::new(input)
+            .with_options(options)
+            .transform_to("rust")
+            .expect("The types should be transformed without an issue");
+        assert!(result.contains("#[serde(rename = \"say \\\"hi\\\"\\n\")]\n    Quote,"));
+        assert!(result.contains("pub amount: rust_decimal::Decimal,"));
+        assert!(result.contains("pub note: serde_json::Value,"))
+    }
+
+    #[test]
+    fn should_transform_every_predeclared_type_to_rust_successfully() {
+        let input = r#"
+        type Sample struct {
+            Small int8 `json:"small"`
+            Unsigned uint16 `json:"unsigned"`
+            Letter rune `json:"letter"`
+            Size uint `json:"size"`
+            ID int64 `json:"id"`
+            Count uint64 `json:"count"`
+            Ratio float32 `json:"ratio"`
+            Signal complex128 `json:"signal"`
+            Signals []complex64 `json:"signals"`
+        }"#;
+        let result = super::Source::new(input)
+            .transform_to("rust")
+            .expect("The struct should be transformed without an issue");
+        assert!(result.contains(
+            "pub struct Sample {\n    pub small: i8,\n    pub unsigned: u16,\n    pub letter: i32,\n    pub size: u64,\n    pub id: i64,\n    pub count: u64,\n    pub ratio: f64,\n}"
+        ));
+    }
+
+    #[test]
+    fn should_flatten_only_the_embedded_structs_whose_fields_all_win_in_rust() {
+        let input = r#"
+        type Base struct {
+            ID int64 `json:"id"`
+            Kind string `json:"kind"`
+        }
+
+        type Named struct {
+            Name string
+            Label string
+        }
+
+        type Labeled struct {
+            Label string
+        }
+
+        type Audit struct {
+            By string `json:"by"`
+        }
+
+        type Account struct {
+            Base
+            Named
+            Labeled
+            Audit
+            ID string `json:"id"`
+        }"#;
+        let result = super::Source::new(input)
+            .transform_to("rust")
+            .expect("The types should be transformed without an issue");
+        // `ID` shadows the promoted `id` and `Label` is ambiguous, their structs are inlined.
+        assert!(result.contains(
+            "pub struct Account {\n    pub kind: String,\n    #[serde(rename = \"Name\")]\n    pub name: String,\n    #[serde(flatten)]\n    pub audit: Audit,\n    pub id: String,\n}"
+        ));
+    }
+
+    #[test]
+    fn should_transform_with_formatting_options_successfully() {
+        let input = r#"
+        type Role string
+        const (
+            RoleAdmin Role = "admin"
+            RoleOwner Role = "it's \"mine\""
+        )
+
+        type Account struct {
+            Name string `json:"full-name"`
+            Meta struct { Page int }
+        }"#;
+        let options = super::Options {
+            formatting: super::Formatting {
+                indent: super::Indent::Tabs,
+                separator: Some(super::Separator::Comma),
+                quotes: super::QuoteStyle::Single,
+                trailing_newline: false,
+            },
+            ..Default::default()
+        };
+        let source = super::Source::new(input).with_options(options);
+        let result = source
+            .transform_to("typescript")
+            .expect("The types should be transformed without an issue");
+        assert!(result.contains(r#"export type Role = 'admin' | 'it\'s "mine"';"#));
+        assert!(result.contains(
+            "export interface Account {\n\t'full-name': string,\n\tMeta: {\n\t\tPage: number,\n\t},\n}"
+        ));
+        assert!(!result.ends_with('\n'));
+
+        let options = super::Options {
+            formatting: super::Formatting {
+                indent: super::Indent::Spaces(4),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let result = super::Source::new(input)
+            .with_options(options)
+            .transform_to("flow")
+            .expect("The types should be transformed without an issue");
+        assert!(result.contains(
+            "export type Account = {\n    \"full-name\": string,\n    Meta: {\n        Page: number,\n    },\n};\n"
+        ))
+    }
 }
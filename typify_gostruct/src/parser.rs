@@ -1,9 +1,12 @@
-use crate::ast::{Field, ParseError, RequiredElements, StructDeclaration, TagKey, TagValue, AST};
+use crate::ast::{
+    ConstDeclaration, ConstValue, Field, ParseError, RequiredElements, StructDeclaration, TagKey,
+    TagValue, TypeDeclaration, TypeParameter, AST,
+};
 use crate::scanner::{Token, TokenWithContext};
 use std::collections::HashMap;
 use std::iter::Peekable;
 
-use super::ast::{self, FieldType};
+use super::ast::{self, DataType, FieldType};
 
 macro_rules! consume_expected_token_with_action {
     ($tokens:expr, $expected:pat, $transform_token:expr, $required_element:expr) => {
@@ -37,50 +40,113 @@ where
 {
     consume_expected_token_with_action!(
         tokens,
-        &Token::Identifier(ref identifier),
+        Token::Identifier(identifier),
         identifier.to_string(),
         RequiredElements::Identifier
     )
 }
 
-fn consume_expected_string_literal<'a, I>(tokens: &mut Peekable<I>) -> Result<String, ParseError>
-where
-    I: Iterator<Item = &'a TokenWithContext>,
-{
-    consume_expected_token_with_action!(
-        tokens,
-        &Token::StringLiteral(ref literal),
-        literal.to_string(),
-        RequiredElements::StringLiteral
-    )
+pub fn parse(tokens: &[TokenWithContext]) -> Result<Vec<AST>, Vec<String>> {
+    let (statements, errors) = parse_partially(tokens);
+    if errors.is_empty() {
+        Ok(statements)
+    } else {
+        Err(errors)
+    }
 }
 
-pub fn parse(tokens: &[TokenWithContext]) -> Result<Vec<AST>, Vec<String>> {
+/// Parses every declaration it can, synchronizing on the next top-level declaration whenever one
+/// is malformed. Returns the declarations that parsed cleanly along with every error found.
+pub fn parse_partially(tokens: &[TokenWithContext]) -> (Vec<AST>, Vec<String>) {
     let mut statements = Vec::new();
     let mut errors = Vec::new();
-    let mut peekable_tokens = tokens.iter().peekable();
+    let mut peekable_tokens = strip_trailing_comments(tokens).into_iter().peekable();
     loop {
         let result = parsing_entrypoint(&mut peekable_tokens);
         match result {
-            Ok(statement) => statements.push(statement),
+            Ok(parsed_statements) => statements.extend(parsed_statements),
             Err(ParseError::UnexpectedEndOfFile) => {
                 break;
             }
             Err(error) => {
                 errors.push(format!("{}", error));
-                // TODO: Enable synchronization for this errors.
-                break;
+                synchronize(&mut peekable_tokens);
             }
         }
     }
-    if errors.is_empty() {
-        Ok(statements)
+    associate_constants(&mut statements);
+    (statements, errors)
+}
+
+/// Discards tokens until the start of the next top-level declaration. `type`, `const`, `var`,
+/// `import` and `package` can't show up inside a struct so they are always a safe place to
+/// resume, whereas `func` is also a field type and only counts at the start of a line.
+fn synchronize<'a, I>(tokens: &mut Peekable<I>)
+where
+    I: Iterator<Item = &'a TokenWithContext>,
+{
+    let mut at_line_start = false;
+    while let Some(token) = tokens.peek() {
+        match token.token {
+            Token::Type | Token::Const | Token::Var | Token::Import | Token::Package => break,
+            Token::Func if at_line_start => break,
+            _ => {}
+        }
+        at_line_start = token.token == Token::NextLine;
+        let _ = tokens.next();
+    }
+}
+
+/// Only comments that start a line can document a declaration or a field. The rest are dropped
+/// up-front so that the parser doesn't have to expect them in between the tokens of a field.
+fn strip_trailing_comments(tokens: &[TokenWithContext]) -> Vec<&TokenWithContext> {
+    let mut starts_line = true;
+    tokens
+        .iter()
+        .filter(|token| {
+            let is_comment = matches!(token.token, Token::Comment(_));
+            let keep = !is_comment || starts_line;
+            starts_line = token.token == Token::NextLine;
+            keep
+        })
+        .collect()
+}
+
+/// Consumes consecutive comment lines, returning them if nothing but a line break separates them
+/// from whatever follows.
+fn parse_doc_comment<'a, I>(tokens: &mut Peekable<I>) -> Option<String>
+where
+    I: Iterator<Item = &'a TokenWithContext>,
+{
+    let mut lines = Vec::new();
+    while let Some(Token::Comment(text)) = tokens.peek().map(|t| &t.token) {
+        lines.push(text.clone());
+        let _ = tokens.next();
+        if let Some(Token::NextLine) = tokens.peek().map(|t| &t.token) {
+            let _ = tokens.next();
+        }
+        // A blank line detaches the comment from the next declaration.
+        if let Some(Token::NextLine) = tokens.peek().map(|t| &t.token) {
+            lines.clear();
+        }
+    }
+    if lines.is_empty() {
+        None
     } else {
-        Err(errors)
+        Some(lines.join("\n"))
     }
 }
 
-fn parsing_entrypoint<'a, I>(tokens: &mut Peekable<I>) -> Result<AST, ParseError>
+fn attach_doc(mut statement: AST, doc: Option<String>) -> AST {
+    match &mut statement {
+        AST::Declaration(declaration) => declaration.doc = doc,
+        AST::Definition(declaration) | AST::Alias(declaration) => declaration.doc = doc,
+        AST::Constant(_) | AST::Field(_) => {}
+    }
+    statement
+}
+
+fn parsing_entrypoint<'a, I>(tokens: &mut Peekable<I>) -> Result<Vec<AST>, ParseError>
 where
     I: Iterator<Item = &'a TokenWithContext>,
 {
@@ -88,7 +154,26 @@ where
     match &element.token {
         Token::Type => {
             let _ = tokens.next();
-            parse_struct_declaration(tokens)
+            parse_type_declarations(tokens, None)
+        }
+        Token::Package | Token::Import | Token::Var | Token::Func => {
+            let _ = tokens.next();
+            skip_declaration(tokens);
+            Ok(Vec::new())
+        }
+        Token::Comment(_) => {
+            let doc = parse_doc_comment(tokens);
+            match tokens.peek().map(|t| &t.token) {
+                Some(Token::Type) => {
+                    let _ = tokens.next();
+                    parse_type_declarations(tokens, doc)
+                }
+                _ => parsing_entrypoint(tokens),
+            }
+        }
+        Token::Const => {
+            let _ = tokens.next();
+            parse_const_declarations(tokens)
         }
         Token::NextLine => {
             let _ = tokens.next();
@@ -98,16 +183,433 @@ where
     }
 }
 
-fn parse_struct_declaration<'a, I>(tokens: &mut Peekable<I>) -> Result<AST, ParseError>
+/// Skips a declaration that isn't translated (`package`, `import`, `var`, `func` and methods)
+/// along with every block it opens, up to the end of its last line.
+fn skip_declaration<'a, I>(tokens: &mut Peekable<I>)
+where
+    I: Iterator<Item = &'a TokenWithContext>,
+{
+    let mut depth = 0;
+    for token in tokens.by_ref() {
+        match token.token {
+            Token::LeftParen | Token::LeftBrace | Token::LeftBracket => depth += 1,
+            Token::RightParen | Token::RightBrace | Token::RightBracket => depth -= 1,
+            Token::NextLine if depth <= 0 => break,
+            _ => {}
+        }
+    }
+}
+
+/// Skips the remainder of a `{ ... }` block whose opening brace was already consumed.
+fn skip_block<'a, I>(tokens: &mut Peekable<I>) -> Result<(), ParseError>
+where
+    I: Iterator<Item = &'a TokenWithContext>,
+{
+    let mut depth = 1;
+    for token in tokens.by_ref() {
+        match token.token {
+            Token::LeftBrace => depth += 1,
+            Token::RightBrace if depth == 1 => return Ok(()),
+            Token::RightBrace => depth -= 1,
+            _ => {}
+        }
+    }
+    Err(ParseError::UnexpectedEndOfFile)
+}
+
+fn parse_type_declarations<'a, I>(
+    tokens: &mut Peekable<I>,
+    doc: Option<String>,
+) -> Result<Vec<AST>, ParseError>
+where
+    I: Iterator<Item = &'a TokenWithContext>,
+{
+    match tokens.peek().map(|t| &t.token) {
+        Some(Token::LeftParen) => {
+            let _ = tokens.next();
+            parse_grouped_type_declarations(tokens)
+        }
+        _ => match parse_type_declaration(tokens) {
+            Ok(declaration) => Ok(vec![attach_doc(declaration, doc)]),
+            // Function and channel types have no JSON representation to declare.
+            Err(ParseError::UnsupportedType(_)) => Ok(Vec::new()),
+            Err(error) => Err(error),
+        },
+    }
+}
+
+fn parse_grouped_type_declarations<'a, I>(tokens: &mut Peekable<I>) -> Result<Vec<AST>, ParseError>
+where
+    I: Iterator<Item = &'a TokenWithContext>,
+{
+    let mut declarations = Vec::new();
+    let mut doc = None;
+    loop {
+        let element = tokens.peek().ok_or(ParseError::UnexpectedEndOfGroup)?;
+        match &element.token {
+            Token::NextLine => {
+                let _ = tokens.next();
+                doc = None;
+            }
+            Token::Comment(_) => doc = parse_doc_comment(tokens),
+            Token::RightParen => {
+                let _ = tokens.next();
+                return Ok(declarations);
+            }
+            _ => match parse_type_declaration(tokens) {
+                Ok(declaration) => declarations.push(attach_doc(declaration, doc.take())),
+                Err(ParseError::UnsupportedType(_)) => doc = None,
+                Err(error) => return Err(error),
+            },
+        }
+    }
+}
+
+fn parse_type_declaration<'a, I>(tokens: &mut Peekable<I>) -> Result<AST, ParseError>
 where
     I: Iterator<Item = &'a TokenWithContext>,
 {
     let identifier = consume_expected_identifier(tokens)?;
+    let mut type_parameters = Vec::new();
+    if let Some(Token::LeftBracket) = tokens.peek().map(|t| &t.token) {
+        let _ = tokens.next();
+        // `type Name [...]` is either a type parameter list or a slice/array definition.
+        let first_name = match tokens.peek().map(|t| &t.token) {
+            Some(Token::RightBracket) => None,
+            _ => Some(consume_expected_identifier(tokens)?),
+        };
+        match (first_name, tokens.peek().map(|t| &t.token)) {
+            (None, _) => {
+                let definition = parse_slice_or_array_type(tokens)?;
+                return Ok(type_definition(identifier, Vec::new(), definition));
+            }
+            (Some(length), Some(Token::RightBracket)) => {
+                let _ = tokens.next();
+                let element_type = parse_field_type(tokens)?;
+                let definition = ast::FieldType::Array(length, Box::new(element_type));
+                return Ok(type_definition(identifier, Vec::new(), definition));
+            }
+            (Some(first_name), _) => type_parameters = parse_type_parameters(tokens, first_name)?,
+        }
+    }
+    match tokens.peek().map(|t| &t.token) {
+        Some(Token::Struct) => parse_struct_declaration(tokens, identifier, type_parameters),
+        Some(Token::Equal) => {
+            let _ = tokens.next();
+            let declaration = TypeDeclaration {
+                name: identifier,
+                doc: None,
+                type_parameters,
+                definition: parse_field_type(tokens)?,
+                variants: Vec::new(),
+            };
+            Ok(AST::Alias(Box::new(declaration)))
+        }
+        _ => {
+            let definition = parse_field_type(tokens)?;
+            Ok(type_definition(identifier, type_parameters, definition))
+        }
+    }
+}
+
+fn type_definition(
+    name: String,
+    type_parameters: Vec<TypeParameter>,
+    definition: FieldType,
+) -> AST {
+    let declaration = TypeDeclaration {
+        name,
+        doc: None,
+        type_parameters,
+        definition,
+        variants: Vec::new(),
+    };
+    AST::Definition(Box::new(declaration))
+}
+
+/// Parses the remainder of a type parameter list (`[K comparable, V any]`) whose first name was
+/// already consumed, up to and including the closing `]`.
+fn parse_type_parameters<'a, I>(
+    tokens: &mut Peekable<I>,
+    first_name: String,
+) -> Result<Vec<TypeParameter>, ParseError>
+where
+    I: Iterator<Item = &'a TokenWithContext>,
+{
+    let mut type_parameters = Vec::new();
+    let mut names = vec![first_name];
+    let mut constraint = Vec::new();
+    let mut depth = 0;
+    loop {
+        let token = tokens.next().ok_or(ParseError::UnexpectedEndOfFile)?;
+        match &token.token {
+            Token::RightBracket if depth == 0 => break,
+            // `K, V any` shares one constraint between several names.
+            Token::Comma if depth == 0 => {
+                if !constraint.is_empty() {
+                    let constraint = parse_type_constraint(&std::mem::take(&mut constraint));
+                    type_parameters.extend(names.drain(..).map(|name| TypeParameter {
+                        name,
+                        constraint: constraint.clone(),
+                    }));
+                }
+                names.push(consume_expected_identifier(tokens)?);
+            }
+            Token::NextLine | Token::Comment(_) => {}
+            Token::LeftBracket | Token::LeftBrace | Token::LeftParen => {
+                depth += 1;
+                constraint.push(token);
+            }
+            Token::RightBracket | Token::RightBrace | Token::RightParen => {
+                depth -= 1;
+                constraint.push(token);
+            }
+            _ => constraint.push(token),
+        }
+    }
+    let constraint = parse_type_constraint(&constraint);
+    type_parameters.extend(names.into_iter().map(|name| TypeParameter {
+        name,
+        constraint: constraint.clone(),
+    }));
+    Ok(type_parameters)
+}
+
+/// Keeps constraints that are a union of predeclared types (`~int | ~string`), anything else
+/// (`any`, `comparable`, interfaces, named constraints) is treated as unconstrained.
+fn parse_type_constraint(tokens: &[&TokenWithContext]) -> Vec<DataType> {
+    let mut constraint = Vec::new();
+    for term in tokens.split(|t| t.token == Token::Pipe) {
+        match term {
+            [TokenWithContext {
+                token: Token::DataType(data_type),
+                ..
+            }]
+            | [TokenWithContext {
+                token: Token::Tilde,
+                ..
+            }, TokenWithContext {
+                token: Token::DataType(data_type),
+                ..
+            }] => constraint.push(data_type.clone()),
+            _ => return Vec::new(),
+        }
+    }
+    constraint
+}
+
+/// The right hand side of a constant. It's remembered so that specs without one can repeat it.
+#[derive(Clone)]
+enum ConstExpression {
+    Literal(ConstValue),
+    Iota(i64),
+    Unsupported,
+}
+
+impl ConstExpression {
+    fn evaluate(&self, iota: i64) -> Option<ConstValue> {
+        match self {
+            ConstExpression::Literal(value) => Some(value.clone()),
+            ConstExpression::Iota(offset) => Some(ConstValue::Integer(iota + offset)),
+            ConstExpression::Unsupported => None,
+        }
+    }
+}
+
+fn parse_const_declarations<'a, I>(tokens: &mut Peekable<I>) -> Result<Vec<AST>, ParseError>
+where
+    I: Iterator<Item = &'a TokenWithContext>,
+{
+    match tokens.peek().map(|t| &t.token) {
+        Some(Token::LeftParen) => {
+            let _ = tokens.next();
+            parse_grouped_const_declarations(tokens)
+        }
+        _ => {
+            let declaration = parse_const_spec(tokens, 0, &mut None)?;
+            Ok(declaration.into_iter().collect())
+        }
+    }
+}
+
+fn parse_grouped_const_declarations<'a, I>(tokens: &mut Peekable<I>) -> Result<Vec<AST>, ParseError>
+where
+    I: Iterator<Item = &'a TokenWithContext>,
+{
+    let mut declarations = Vec::new();
+    let mut previous = None;
+    let mut iota = 0;
+    loop {
+        let element = tokens.peek().ok_or(ParseError::UnexpectedEndOfGroup)?;
+        match &element.token {
+            Token::NextLine => {
+                let _ = tokens.next();
+            }
+            Token::Comment(_) => {
+                let _ = parse_doc_comment(tokens);
+            }
+            Token::RightParen => {
+                let _ = tokens.next();
+                return Ok(declarations);
+            }
+            _ => {
+                declarations.extend(parse_const_spec(tokens, iota, &mut previous)?);
+                iota += 1;
+            }
+        }
+    }
+}
+
+fn parse_const_spec<'a, I>(
+    tokens: &mut Peekable<I>,
+    iota: i64,
+    previous: &mut Option<(Option<String>, ConstExpression)>,
+) -> Result<Option<AST>, ParseError>
+where
+    I: Iterator<Item = &'a TokenWithContext>,
+{
+    let name = consume_expected_identifier(tokens)?;
+    let line = consume_until_line_end(tokens);
+    let (type_name, expression) = match line.iter().position(|t| t.token == Token::Equal) {
+        Some(index) => {
+            let type_name = match &line[..index] {
+                [] => Some(None),
+                [type_token] => match &type_token.token {
+                    Token::Identifier(_) | Token::DataType(_) => {
+                        Some(Some(type_token.lexeme.clone()))
+                    }
+                    _ => None,
+                },
+                _ => None,
+            };
+            match type_name {
+                Some(type_name) => (type_name, parse_const_expression(&line[index + 1..])),
+                None => (None, ConstExpression::Unsupported),
+            }
+        }
+        // A spec without an expression repeats the previous one, this is how `iota` progresses.
+        None if line.is_empty() => previous
+            .clone()
+            .unwrap_or((None, ConstExpression::Unsupported)),
+        None => (None, ConstExpression::Unsupported),
+    };
+    *previous = Some((type_name.clone(), expression.clone()));
+
+    if name == "_" {
+        return Ok(None);
+    }
+    let declaration = ConstDeclaration {
+        name,
+        type_name,
+        value: expression.evaluate(iota),
+    };
+    Ok(Some(AST::Constant(Box::new(declaration))))
+}
+
+fn parse_const_expression(tokens: &[&TokenWithContext]) -> ConstExpression {
+    match tokens {
+        [TokenWithContext {
+            token: Token::StringLiteral(literal),
+            ..
+        }] => return ConstExpression::Literal(ConstValue::String(literal.clone())),
+        [TokenWithContext {
+            token: Token::RawStringLiteral(literal),
+            ..
+        }] => return ConstExpression::Literal(ConstValue::String(escape_raw_string(literal))),
+        _ => {}
+    }
+    let expression: String = tokens.iter().map(|t| t.lexeme.as_str()).collect();
+    let iota_offset = match expression.strip_prefix("iota") {
+        Some("") => Some(0),
+        Some(offset) => offset
+            .strip_prefix('+')
+            .unwrap_or(offset)
+            .parse::<i64>()
+            .ok(),
+        None => None,
+    };
+    match (iota_offset, expression.parse::<i64>()) {
+        (Some(offset), _) => ConstExpression::Iota(offset),
+        (None, Ok(value)) => ConstExpression::Literal(ConstValue::Integer(value)),
+        (None, Err(_)) => ConstExpression::Unsupported,
+    }
+}
+
+/// Rewrites the body of a raw string literal as the body of an interpreted one, so every
+/// string constant carries the same escaping. Go discards carriage returns in raw strings.
+fn escape_raw_string(literal: &str) -> String {
+    let mut escaped = String::with_capacity(literal.len());
+    for c in literal.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Consumes the rest of the current line, leaving a closing `)` of an enclosing group in place.
+fn consume_until_line_end<'a, I>(tokens: &mut Peekable<I>) -> Vec<&'a TokenWithContext>
+where
+    I: Iterator<Item = &'a TokenWithContext>,
+{
+    let mut line = Vec::new();
+    let mut depth = 0;
+    while let Some(token) = tokens.peek() {
+        match token.token {
+            Token::NextLine => {
+                let _ = tokens.next();
+                break;
+            }
+            Token::RightParen if depth == 0 => break,
+            Token::RightParen => depth -= 1,
+            Token::LeftParen => depth += 1,
+            _ => {}
+        }
+        line.extend(tokens.next());
+    }
+    line
+}
+
+/// Hands every constant over to the defined type it was declared with.
+fn associate_constants(statements: &mut [AST]) {
+    let constants: Vec<ConstDeclaration> = statements
+        .iter()
+        .filter_map(|statement| match statement {
+            AST::Constant(constant) => Some(constant.as_ref().clone()),
+            _ => None,
+        })
+        .collect();
+    for statement in statements.iter_mut() {
+        if let AST::Definition(declaration) | AST::Alias(declaration) = statement {
+            declaration.variants = constants
+                .iter()
+                .filter(|constant| constant.type_name.as_ref() == Some(&declaration.name))
+                .cloned()
+                .collect();
+        }
+    }
+}
+
+fn parse_struct_declaration<'a, I>(
+    tokens: &mut Peekable<I>,
+    identifier: String,
+    type_parameters: Vec<TypeParameter>,
+) -> Result<AST, ParseError>
+where
+    I: Iterator<Item = &'a TokenWithContext>,
+{
     consume_expected_token!(tokens, &Token::Struct, RequiredElements::Struct)?;
     consume_expected_token!(tokens, &Token::LeftBrace, RequiredElements::LeftBrace)?;
     let block = parse_struct_body(tokens)?;
     let declaration = StructDeclaration {
         name: identifier,
+        doc: None,
+        type_parameters,
         body: block,
     };
     Ok(AST::Declaration(Box::new(declaration)))
@@ -126,17 +628,17 @@ where
                 ..
             })
         )
-    };
+    }
     while !is_block_end(tokens.peek()) {
-        let statement = parse_struct_field(tokens)?;
-        statements.push(statement)
+        statements.extend(parse_struct_fields(tokens)?);
     }
 
     let _ = tokens.next();
     Ok(statements)
 }
 
-fn parse_struct_field<'a, I>(tokens: &mut Peekable<I>) -> Result<Field, ParseError>
+/// Parses the next field declaration, which declares one field per name (`X, Y int`).
+fn parse_struct_fields<'a, I>(tokens: &mut Peekable<I>) -> Result<Vec<Field>, ParseError>
 where
     I: Iterator<Item = &'a TokenWithContext>,
 {
@@ -145,21 +647,48 @@ where
     match &element.token {
         Token::Identifier(identifier) => {
             let _ = tokens.next();
-            let (field_type, field_tags) = parse_field_type_with_tags(tokens)?;
-            let field_name = ast::FieldName(identifier.to_string());
-
-            if field_tags.is_empty() {
-                let field = ast::Field::Plain(field_name, field_type);
-                Ok(field)
-            } else {
-                Ok(ast::Field::WithTags(field_name, field_type, field_tags))
+            let mut names = vec![identifier.to_string()];
+            while let Some(Token::Comma) = tokens.peek().map(|t| &t.token) {
+                let _ = tokens.next();
+                names.push(consume_expected_identifier(tokens)?);
             }
+            let (field_type, field_tags) = match parse_field_type_with_tags(tokens) {
+                Ok(parsed) => parsed,
+                // encoding/json can't marshal functions and channels, so such fields are dropped.
+                Err(ParseError::UnsupportedType(_)) => return Ok(Vec::new()),
+                Err(error) => return Err(error),
+            };
+            let fields = names.into_iter().map(|name| {
+                let field_name = ast::FieldName(name);
+                if field_tags.is_empty() {
+                    ast::Field::Plain(field_name, field_type.clone())
+                } else {
+                    ast::Field::WithTags(field_name, field_type.clone(), field_tags.clone())
+                }
+            });
+            Ok(fields.collect())
+        }
+        Token::Pointer => {
+            // Embedded pointers (`*Base`) promote their fields the same way `Base` does.
+            let _ = tokens.next();
+            parse_struct_fields(tokens)
         }
         Token::NextLine => {
             let _ = tokens.next();
-            parse_struct_field(tokens)
+            parse_struct_fields(tokens)
         }
-        Token::RightBrace => Ok(ast::Field::Blank),
+        Token::Comment(_) => {
+            let doc = parse_doc_comment(tokens);
+            let fields = parse_struct_fields(tokens)?;
+            let documented = fields.into_iter().map(|field| match (&doc, field) {
+                (Some(doc), field @ (Field::Plain(..) | Field::WithTags(..))) => {
+                    ast::Field::Documented(doc.clone(), Box::new(field))
+                }
+                (_, field) => field,
+            });
+            Ok(documented.collect())
+        }
+        Token::RightBrace => Ok(vec![ast::Field::Blank]),
         _ => Err(ParseError::UnknownElement(element.lexeme.clone())),
     }
 }
@@ -170,110 +699,287 @@ fn parse_field_type_with_tags<'a, I>(
 where
     I: Iterator<Item = &'a TokenWithContext>,
 {
-    let item = tokens.peek().ok_or(ParseError::UnexpectedEndOfFile)?;
+    let item = *tokens.peek().ok_or(ParseError::UnexpectedEndOfFile)?;
     match &item.token {
-        Token::DataType(specified_type) => {
+        Token::NextLine => {
             let _ = tokens.next();
-            let field_type = ast::FieldType::One(specified_type.clone());
-            let field_tags = parse_field_tags_if_present(tokens)?;
-            Ok((field_type, field_tags))
+            let field_type = ast::FieldType::Named(ast::DataType::Embedded);
+            Ok((field_type, HashMap::new()))
         }
-        Token::Identifier(literal) => {
+        Token::RawStringLiteral(tag) => {
             let _ = tokens.next();
-
-            let field_type = ast::FieldType::One(ast::DataType::Custom(literal.to_string()));
+            let field_type = ast::FieldType::Named(ast::DataType::Embedded);
+            let res = parse_field_tags(item, tag)?;
+            Ok((field_type, res))
+        }
+        Token::RightBrace => {
+            let field_type = ast::FieldType::Named(ast::DataType::Embedded);
+            Ok((field_type, HashMap::new()))
+        }
+        _ => {
+            // The tags of an unsupported field are consumed before it is reported.
+            let field_type = parse_field_type(tokens);
             let field_tags = parse_field_tags_if_present(tokens)?;
+            Ok((field_type?, field_tags))
+        }
+    }
+}
 
-            Ok((field_type, field_tags))
+/// Parses the content of a struct tag (`json:"country" binding:"required"`) the same way
+/// `reflect.StructTag` reads it.
+fn parse_field_tags(
+    tag: &TokenWithContext,
+    content: &str,
+) -> Result<HashMap<TagKey, TagValue>, ParseError> {
+    let missing = |element| ParseError::Missing(element, tag.lexeme.clone(), tag.position);
+    let mut field_tags = HashMap::new();
+    let mut rest = content.trim_start();
+    while !rest.is_empty() {
+        let name_length = rest
+            .find(|c: char| c <= ' ' || c == ':' || c == '"')
+            .unwrap_or(rest.len());
+        let (name, remainder) = rest.split_at(name_length);
+        if name.is_empty() {
+            return Err(missing(RequiredElements::Identifier));
         }
-        Token::NextLine => {
+        let remainder = remainder
+            .strip_prefix(':')
+            .ok_or_else(|| missing(RequiredElements::Colon))?;
+        let remainder = remainder
+            .strip_prefix('"')
+            .ok_or_else(|| missing(RequiredElements::StringLiteral))?;
+
+        let mut value = String::new();
+        let mut end = None;
+        let mut characters = remainder.char_indices();
+        while let Some((index, character)) = characters.next() {
+            match character {
+                '\\' => value.extend(characters.next().map(|(_, escaped)| escaped)),
+                '"' => {
+                    end = Some(index + 1);
+                    break;
+                }
+                character => value.push(character),
+            }
+        }
+        let end = end.ok_or_else(|| missing(RequiredElements::StringLiteral))?;
+        field_tags.insert(TagKey(name.to_string()), TagValue(value));
+        rest = remainder[end..].trim_start();
+    }
+    Ok(field_tags)
+}
+
+fn parse_field_type<'a, I>(tokens: &mut Peekable<I>) -> Result<FieldType, ParseError>
+where
+    I: Iterator<Item = &'a TokenWithContext>,
+{
+    let token = tokens.peek().ok_or(ParseError::UnexpectedEndOfFile)?;
+    match &token.token {
+        Token::DataType(specified_type) => {
             let _ = tokens.next();
-            let field_type = ast::FieldType::One(ast::DataType::Embedded);
-            Ok((field_type, HashMap::new()))
+            Ok(ast::FieldType::Named(specified_type.clone()))
         }
-        Token::Graveaccent => {
+        Token::Identifier(identifier) => {
             let _ = tokens.next();
-            let field_type = ast::FieldType::One(ast::DataType::Embedded);
-            let res = parse_field_tags(tokens)?;
-            Ok((field_type, res))
+            let named_type = parse_named_type(identifier);
+            match tokens.peek().map(|t| &t.token) {
+                Some(Token::LeftBracket) => {
+                    let _ = tokens.next();
+                    let type_arguments = parse_type_arguments(tokens)?;
+                    Ok(ast::FieldType::Instantiated(
+                        Box::new(named_type),
+                        type_arguments,
+                    ))
+                }
+                _ => Ok(named_type),
+            }
+        }
+        Token::Pointer => {
+            let _ = tokens.next();
+            let pointee = parse_field_type(tokens)?;
+            Ok(ast::FieldType::Pointer(Box::new(pointee)))
         }
         Token::LeftBracket => {
             let _ = tokens.next();
-            consume_expected_token!(tokens, &Token::RightBracket, RequiredElements::RightBracket)?;
-            let field_type = parse_type_of_list_with_field(tokens)?;
-            let field_tags = parse_field_tags_if_present(tokens)?;
-            Ok((field_type, field_tags))
+            parse_slice_or_array_type(tokens)
+        }
+        Token::Map => {
+            let _ = tokens.next();
+            parse_map_type(tokens)
+        }
+        Token::Interface => {
+            // Interfaces marshal as whatever value they hold, their method sets don't matter.
+            let _ = tokens.next();
+            consume_expected_token!(tokens, &Token::LeftBrace, RequiredElements::LeftBrace)?;
+            skip_block(tokens)?;
+            Ok(ast::FieldType::Named(DataType::Custom("any".to_string())))
         }
-        _token => Err(ParseError::UnknownElement(item.lexeme.to_string())),
+        Token::Struct => {
+            let _ = tokens.next();
+            consume_expected_token!(tokens, &Token::LeftBrace, RequiredElements::LeftBrace)?;
+            let block = parse_struct_body(tokens)?;
+            let declaration = StructDeclaration {
+                name: String::new(),
+                doc: None,
+                type_parameters: Vec::new(),
+                body: block,
+            };
+            Ok(ast::FieldType::Struct(Box::new(declaration)))
+        }
+        Token::Func => {
+            let _ = tokens.next();
+            skip_function_signature(tokens)?;
+            Err(ParseError::UnsupportedType("func".to_string()))
+        }
+        Token::Chan => {
+            // `chan T` or `chan<- T`
+            let _ = tokens.next();
+            if let Some(Token::Arrow) = tokens.peek().map(|t| &t.token) {
+                let _ = tokens.next();
+            }
+            skip_unsupported_type(tokens)?;
+            Err(ParseError::UnsupportedType("chan".to_string()))
+        }
+        Token::Arrow => {
+            // `<-chan T`
+            let _ = tokens.next();
+            consume_expected_token!(tokens, &Token::Chan, RequiredElements::Chan)?;
+            skip_unsupported_type(tokens)?;
+            Err(ParseError::UnsupportedType("chan".to_string()))
+        }
+        _ => Err(ParseError::UnexpectedElement(token.lexeme.clone())),
     }
 }
 
-fn parse_field_tags<'a, I>(
-    tokens: &mut Peekable<I>,
-) -> Result<HashMap<TagKey, TagValue>, ParseError>
+/// Consumes a type whose fields are dropped anyway, whether it is supported or not.
+fn skip_unsupported_type<'a, I>(tokens: &mut Peekable<I>) -> Result<(), ParseError>
 where
     I: Iterator<Item = &'a TokenWithContext>,
 {
-    let mut json_tags = HashMap::new();
+    match parse_field_type(tokens) {
+        Ok(_) | Err(ParseError::UnsupportedType(_)) => Ok(()),
+        Err(error) => Err(error),
+    }
+}
 
-    fn is_block_end(t: Option<&&TokenWithContext>) -> bool {
-        matches!(
-            t,
-            Some(&TokenWithContext {
-                token: Token::Graveaccent,
-                ..
-            })
-        )
-    };
-    while !is_block_end(tokens.peek()) {
-        let identifier = consume_expected_identifier(tokens)?;
-        let identifier = TagKey(identifier);
-        consume_expected_token!(tokens, &Token::Colon, RequiredElements::Colon)?;
-        let tag_value = consume_expected_string_literal(tokens)?;
-        let tag_value = TagValue(tag_value);
-        json_tags.insert(identifier, tag_value);
+/// Consumes the parameters and the results of a `func` type.
+fn skip_function_signature<'a, I>(tokens: &mut Peekable<I>) -> Result<(), ParseError>
+where
+    I: Iterator<Item = &'a TokenWithContext>,
+{
+    consume_expected_token!(tokens, &Token::LeftParen, RequiredElements::LeftParen)?;
+    skip_parameters(tokens)?;
+    match tokens.peek().map(|t| &t.token) {
+        Some(Token::LeftParen) => {
+            let _ = tokens.next();
+            skip_parameters(tokens)
+        }
+        Some(
+            Token::DataType(_)
+            | Token::Identifier(_)
+            | Token::Pointer
+            | Token::LeftBracket
+            | Token::Map
+            | Token::Interface
+            | Token::Struct
+            | Token::Func
+            | Token::Chan
+            | Token::Arrow,
+        ) => skip_unsupported_type(tokens),
+        _ => Ok(()),
+    }
+}
+
+/// Skips the remainder of a `( ... )` list whose opening parenthesis was already consumed.
+fn skip_parameters<'a, I>(tokens: &mut Peekable<I>) -> Result<(), ParseError>
+where
+    I: Iterator<Item = &'a TokenWithContext>,
+{
+    let mut depth = 1;
+    for token in tokens.by_ref() {
+        match token.token {
+            Token::LeftParen => depth += 1,
+            Token::RightParen if depth == 1 => return Ok(()),
+            Token::RightParen => depth -= 1,
+            _ => {}
+        }
+    }
+    Err(ParseError::UnexpectedEndOfFile)
+}
+
+fn parse_type_arguments<'a, I>(tokens: &mut Peekable<I>) -> Result<Vec<FieldType>, ParseError>
+where
+    I: Iterator<Item = &'a TokenWithContext>,
+{
+    let mut type_arguments = vec![parse_field_type(tokens)];
+    while let Some(Token::Comma) = tokens.peek().map(|t| &t.token) {
+        let _ = tokens.next();
+        type_arguments.push(parse_field_type(tokens));
+    }
+    consume_expected_token!(tokens, &Token::RightBracket, RequiredElements::RightBracket)?;
+    type_arguments.into_iter().collect()
+}
+
+fn parse_named_type(identifier: &str) -> FieldType {
+    match identifier.split_once('.') {
+        Some((package, name)) => ast::FieldType::Qualified(package.to_string(), name.to_string()),
+        None => ast::FieldType::Named(DataType::Custom(identifier.to_string())),
     }
-    let _ = tokens.next();
-    Ok(json_tags)
 }
 
-fn parse_type_of_list_with_field<'a, I>(tokens: &mut Peekable<I>) -> Result<FieldType, ParseError>
+fn parse_slice_or_array_type<'a, I>(tokens: &mut Peekable<I>) -> Result<FieldType, ParseError>
 where
     I: Iterator<Item = &'a TokenWithContext>,
 {
     let token = tokens.peek().ok_or(ParseError::UnexpectedEndOfFile)?;
     match &token.token {
-        Token::DataType(specified_type) => {
+        Token::RightBracket => {
             let _ = tokens.next();
-            let specified_type = ast::FieldType::List(specified_type.clone());
-            Ok(specified_type)
+            let element_type = parse_field_type(tokens)?;
+            Ok(ast::FieldType::Slice(Box::new(element_type)))
         }
-        Token::Identifier(custom_type) => {
-            let _ = tokens.next();
-            let specified_type = ast::FieldType::List(ast::DataType::Custom(custom_type.clone()));
-            Ok(specified_type)
+        _ => {
+            let length = consume_expected_identifier(tokens)?;
+            consume_expected_token!(tokens, &Token::RightBracket, RequiredElements::RightBracket)?;
+            let element_type = parse_field_type(tokens)?;
+            Ok(ast::FieldType::Array(length, Box::new(element_type)))
         }
-        _ => Err(ParseError::UnexpectedElement(token.lexeme.clone())),
     }
 }
 
+fn parse_map_type<'a, I>(tokens: &mut Peekable<I>) -> Result<FieldType, ParseError>
+where
+    I: Iterator<Item = &'a TokenWithContext>,
+{
+    consume_expected_token!(tokens, &Token::LeftBracket, RequiredElements::LeftBracket)?;
+    // Channels are valid keys, the rest of the map is consumed before one is reported.
+    let key_type = parse_field_type(tokens);
+    consume_expected_token!(tokens, &Token::RightBracket, RequiredElements::RightBracket)?;
+    let value_type = parse_field_type(tokens)?;
+    Ok(ast::FieldType::Map(
+        Box::new(key_type?),
+        Box::new(value_type),
+    ))
+}
+
 fn parse_field_tags_if_present<'a, I>(
     tokens: &mut Peekable<I>,
 ) -> Result<HashMap<TagKey, TagValue>, ParseError>
 where
     I: Iterator<Item = &'a TokenWithContext>,
 {
-    let current_element = tokens.peek().ok_or(ParseError::UnexpectedEndOfFile)?;
+    let current_element = *tokens.peek().ok_or(ParseError::UnexpectedEndOfFile)?;
     match &current_element.token {
-        Token::Graveaccent => {
+        Token::RawStringLiteral(tag) => {
             let _ = tokens.next();
-            let res = parse_field_tags(tokens)?;
+            let res = parse_field_tags(current_element, tag)?;
             Ok(res)
         }
         Token::NextLine => {
             let _ = tokens.next();
             Ok(HashMap::new())
         }
+        Token::RightBrace => Ok(HashMap::new()),
         _ => Err(ParseError::UnexpectedElement(
             current_element.lexeme.clone(),
         )),
@@ -284,7 +990,8 @@ where
 mod tests {
 
     use crate::{
-        parser::parse,
+        ast::{ConstValue, Field, TagKey, AST},
+        parser::{parse, parse_partially},
         scanner::{self},
     };
 
@@ -329,6 +1036,399 @@ mod tests {
         assert_eq!(parsed_result.len(), 1)
     }
 
+    #[test]
+    fn test_should_parse_grouped_type_declarations_correctly() {
+        let valid_structs = r#"
+        type (
+            Region struct {
+                Country string `json:"country"`
+            }
+            City struct { Name string }; Street struct {
+                Name string
+            }
+        )
+    "#;
+        let tokens = scanner::scan(valid_structs).expect("to be scanned correctly");
+        let parsed_result = parse(&tokens).expect("The structs should be parsed correctly");
+        assert_eq!(parsed_result.len(), 3)
+    }
+
+    #[test]
+    fn test_all_errors_returned_after_synchronizing() {
+        let invalid_example = r#"
+        type Region struct {
+          Country string `json:country`
+        }
+
+        type City struct {
+          Name string
+        }
+
+        type Street struct {
+          Name string `json:`
+        }
+
+        type Address struct {
+          City City
+        "#;
+        let tokens = scanner::scan(invalid_example).expect("to be scanned correctly");
+        let err = parse(&tokens).expect_err("the malformed structs should fail");
+        assert_eq!(err.len(), 3);
+
+        let (parsed_result, errors) = parse_partially(&tokens);
+        assert_eq!(errors, err);
+        match &parsed_result[..] {
+            [AST::Declaration(declaration)] => assert_eq!(declaration.name, "City"),
+            _ => panic!("Only City should be parsed"),
+        }
+    }
+
+    #[test]
+    fn test_should_resume_after_unclosed_struct() {
+        let invalid_example = r#"
+        type Region struct {
+          Country string
+        type City struct {
+          Name string
+        }
+        "#;
+        let tokens = scanner::scan(invalid_example).expect("to be scanned correctly");
+        let (parsed_result, errors) = parse_partially(&tokens);
+        assert_eq!(errors.len(), 1);
+        match &parsed_result[..] {
+            [AST::Declaration(declaration)] => assert_eq!(declaration.name, "City"),
+            _ => panic!("City should still be parsed"),
+        }
+    }
+
+    #[test]
+    fn test_error_returned_with_unclosed_type_group() {
+        let invalid_example = r#"
+        type (
+            Region struct {
+                Country string
+            }
+    "#;
+        let tokens = scanner::scan(invalid_example).expect("to be scanned correctly");
+        let parsed_result = parse(&tokens);
+        assert!(parsed_result.is_err())
+    }
+
+    #[test]
+    fn test_should_parse_defined_types_and_aliases_correctly() {
+        let valid_types = r#"
+        type Status string
+        type UserID = uuid.UUID
+        type (
+            Tags []string
+            Lookup = map[string]int
+        )
+    "#;
+        let tokens = scanner::scan(valid_types).expect("to be scanned correctly");
+        let parsed_result = parse(&tokens).expect("The types should be parsed correctly");
+        assert_eq!(parsed_result.len(), 4)
+    }
+
+    #[test]
+    fn test_should_associate_constants_with_their_type() {
+        let valid_types = r#"
+        type Role string
+        const (
+            RoleAdmin Role = "admin"
+            RoleUser  Role = "user"
+        )
+        type Level int
+        const (
+            _ Level = iota
+            Low
+            High
+        )
+        const Answer = 42
+    "#;
+        let tokens = scanner::scan(valid_types).expect("to be scanned correctly");
+        let parsed_result = parse(&tokens).expect("The types should be parsed correctly");
+        let variants: Vec<_> = parsed_result
+            .iter()
+            .filter_map(|statement| match statement {
+                AST::Definition(declaration) => Some(
+                    declaration
+                        .variants
+                        .iter()
+                        .map(|variant| (variant.name.clone(), variant.value.clone()))
+                        .collect::<Vec<_>>(),
+                ),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            variants,
+            vec![
+                vec![
+                    (
+                        "RoleAdmin".to_string(),
+                        Some(ConstValue::String("admin".into()))
+                    ),
+                    (
+                        "RoleUser".to_string(),
+                        Some(ConstValue::String("user".into()))
+                    )
+                ],
+                vec![
+                    ("Low".to_string(), Some(ConstValue::Integer(1))),
+                    ("High".to_string(), Some(ConstValue::Integer(2)))
+                ]
+            ]
+        )
+    }
+
+    #[test]
+    fn test_should_parse_generic_declarations_correctly() {
+        let valid_types = r#"
+        type Page[T any] struct {
+            Items []T
+            Next  *string
+        }
+        type Pair[K comparable, V ~int | ~float64] struct {
+            Key   K
+            Value V
+        }
+        type List[T any] []T
+        type Buffer [4]byte
+        type Tags []string
+        type Listing struct {
+            Users Page[User]
+            Score Pair[string, float64]
+        }
+    "#;
+        let tokens = scanner::scan(valid_types).expect("to be scanned correctly");
+        let parsed_result = parse(&tokens).expect("The types should be parsed correctly");
+        assert_eq!(parsed_result.len(), 6);
+        match &parsed_result[1] {
+            AST::Declaration(declaration) => {
+                let type_parameters: Vec<_> = declaration
+                    .type_parameters
+                    .iter()
+                    .map(|parameter| (parameter.name.as_str(), parameter.constraint.len()))
+                    .collect();
+                assert_eq!(type_parameters, vec![("K", 0), ("V", 2)])
+            }
+            _ => panic!("Pair should be parsed as a struct declaration"),
+        }
+    }
+
+    #[test]
+    fn test_should_attach_doc_comments() {
+        let valid_struct = r#"
+        // Region is where an account lives.
+        // It's derived from the billing address.
+        type Region struct {
+            /* The ISO country code */
+            Country string `json:"country"` // a trailing comment
+
+            // A detached comment
+
+            State string /* inline */ `json:"state"`
+        }
+
+        // Status is detached from the declaration.
+
+        type Status string
+    "#;
+        let tokens = scanner::scan(valid_struct).expect("to be scanned correctly");
+        let parsed_result = parse(&tokens).expect("The struct should be parsed correctly");
+        match &parsed_result[..] {
+            [AST::Declaration(region), AST::Definition(status)] => {
+                assert_eq!(
+                    region.doc.as_deref(),
+                    Some(
+                        "Region is where an account lives.\nIt's derived from the billing address."
+                    )
+                );
+                assert!(matches!(
+                    &region.body[..],
+                    [Field::Documented(doc, _), Field::WithTags(..), Field::Blank]
+                        if doc == "The ISO country code"
+                ));
+                assert_eq!(status.doc, None);
+            }
+            _ => panic!("A struct and a defined type should be parsed"),
+        }
+    }
+
+    #[test]
+    fn test_should_parse_whole_go_source_files() {
+        let source_file = r#"
+package models
+
+import "encoding/json"
+
+import (
+    "fmt"
+    str "strings"
+)
+
+var (
+    ErrNotFound = fmt.Errorf("not found: %q", "{")
+    defaults    = map[string]int{"a": 1}
+)
+
+var separator = '}'
+
+// Region is where an account lives.
+type Region struct {
+    Country string `json:"country" binding:"required"`
+    Extra   interface{} `json:"extra"`
+}
+
+type Stringer interface {
+    String() string
+}
+
+func (r *Region) String() string {
+    if r.Country == "" {
+        return fmt.Sprintf("{"region": %q}", `{`)
+    }
+    return str.ToUpper(r.Country)
+}
+
+func Parse[T any](data []byte) (T, error) {
+    var value T
+    err := json.Unmarshal(data, &value)
+    return value, err
+}
+
+type City struct {
+    Name string
+}
+"#;
+        let tokens = scanner::scan(source_file).expect("to be scanned correctly");
+        let parsed_result = parse(&tokens).expect("The source file should be parsed correctly");
+        let names: Vec<_> = parsed_result
+            .iter()
+            .filter_map(|statement| match statement {
+                AST::Declaration(declaration) => Some(declaration.name.as_str()),
+                AST::Definition(declaration) => Some(declaration.name.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(names, vec!["Region", "Stringer", "City"])
+    }
+
+    fn field_names(field: &Field) -> Option<&str> {
+        match field {
+            Field::Plain(name, _) | Field::WithTags(name, _, _) => Some(name.0.as_str()),
+            Field::Documented(_, field) => field_names(field),
+            Field::Blank => None,
+        }
+    }
+
+    #[test]
+    fn test_should_parse_one_field_per_name() {
+        let valid_struct = r#"
+        type Point struct {
+            // X and Y are in pixels.
+            X, Y int `json:",omitempty"`
+            Label string
+        }
+    "#;
+        let tokens = scanner::scan(valid_struct).expect("to be scanned correctly");
+        let parsed_result = parse(&tokens).expect("The struct should be parsed correctly");
+        match &parsed_result[..] {
+            [AST::Declaration(declaration)] => {
+                let names: Vec<_> = declaration.body.iter().filter_map(field_names).collect();
+                assert_eq!(names, vec!["X", "Y", "Label"]);
+                assert!(matches!(
+                    &declaration.body[..],
+                    [Field::Documented(_, x), Field::Documented(_, y), ..]
+                        if matches!(**x, Field::WithTags(..)) && matches!(**y, Field::WithTags(..))
+                ));
+            }
+            _ => panic!("A struct should be parsed"),
+        }
+    }
+
+    #[test]
+    fn test_should_drop_function_and_channel_fields() {
+        let valid_struct = r#"
+        type Handler func(w http.ResponseWriter, r *http.Request)
+        type Worker struct {
+            Name     string
+            OnDone   func(err error) `json:"-"`
+            Run      func(ctx context.Context, jobs ...Job) (int, error)
+            Factory  func() func() int
+            Jobs     chan Job
+            Results  <-chan *Result
+            Errors   chan<- error
+            Handlers map[string]func()
+            Queues   []chan int
+            Active   bool
+        }
+    "#;
+        let tokens = scanner::scan(valid_struct).expect("to be scanned correctly");
+        let parsed_result = parse(&tokens).expect("The struct should be parsed correctly");
+        match &parsed_result[..] {
+            [AST::Declaration(declaration)] => {
+                let names: Vec<_> = declaration.body.iter().filter_map(field_names).collect();
+                assert_eq!(names, vec!["Name", "Active"]);
+            }
+            _ => panic!("Only the struct should be parsed"),
+        }
+    }
+
+    #[test]
+    fn test_should_parse_unicode_identifiers() {
+        let valid_struct = r#"
+        type Café struct {
+            Größe  int
+            Straße string
+        }
+    "#;
+        let tokens = scanner::scan(valid_struct).expect("to be scanned correctly");
+        let parsed_result = parse(&tokens).expect("The struct should be parsed correctly");
+        match &parsed_result[..] {
+            [AST::Declaration(declaration)] => {
+                assert_eq!(declaration.name, "Café");
+                let names: Vec<_> = declaration.body.iter().filter_map(field_names).collect();
+                assert_eq!(names, vec!["Größe", "Straße"]);
+            }
+            _ => panic!("A struct should be parsed"),
+        }
+    }
+
+    #[test]
+    fn test_should_parse_struct_tags_like_reflect() {
+        let valid_struct = r#"
+        type Region struct {
+            Country string `json:"country,omitempty"   binding:"required" note:"a \"quoted\" value"`
+        }
+    "#;
+        let tokens = scanner::scan(valid_struct).expect("to be scanned correctly");
+        let parsed_result = parse(&tokens).expect("The struct should be parsed correctly");
+        match &parsed_result[..] {
+            [AST::Declaration(declaration)] => match &declaration.body[..] {
+                [Field::WithTags(_, _, tags), ..] => {
+                    let tag = |key: &str| tags.get(&TagKey(key.to_string())).map(|v| v.0.as_str());
+                    assert_eq!(tag("json"), Some("country,omitempty"));
+                    assert_eq!(tag("binding"), Some("required"));
+                    assert_eq!(tag("note"), Some("a \"quoted\" value"));
+                }
+                _ => panic!("Country should have tags"),
+            },
+            _ => panic!("A struct should be parsed"),
+        }
+    }
+
+    #[test]
+    fn test_error_returned_with_malformed_struct_tags() {
+        let invalid_struct = r#"
+        type Region struct {
+            Country string `json:country`
+        }
+    "#;
+        let tokens = scanner::scan(invalid_struct).expect("to be scanned correctly");
+        assert!(parse(&tokens).is_err())
+    }
+
     #[test]
     fn test_should_parse_struct_with_list_correctly() {
         let valid_struct = r#"
@@ -354,4 +1454,55 @@ mod tests {
         let parsed_result = parse(&tokens);
         assert!(parsed_result.is_ok())
     }
+
+    #[test]
+    fn test_should_parse_struct_with_maps_correctly() {
+        let valid_struct = r#"
+            type Catalog struct {
+                Labels    map[string]string `json:"labels"`
+                Items     map[string]*Item
+                Groups    map[string][]int
+                Nested    map[string]map[int]bool
+            }
+        "#;
+        let tokens = scanner::scan(valid_struct).expect("to be scanned correctly");
+
+        let parsed_result = parse(&tokens);
+        assert!(parsed_result.is_ok())
+    }
+
+    #[test]
+    fn test_should_parse_anonymous_structs_correctly() {
+        let valid_struct = r#"
+            type Response struct {
+                Meta struct { Page int; Total int } `json:"meta"`
+                Items []struct {
+                    ID    string `json:"id"`
+                    Owner struct{ Name string }
+                } `json:"items"`
+                Empty struct{}
+            }
+        "#;
+        let tokens = scanner::scan(valid_struct).expect("to be scanned correctly");
+
+        let parsed_result = parse(&tokens).expect("The struct should be parsed correctly");
+        assert_eq!(parsed_result.len(), 1)
+    }
+
+    #[test]
+    fn test_should_parse_nested_type_expressions_correctly() {
+        let valid_struct = r#"
+            type Matrix struct {
+                Rows      [][]string
+                Groups    []map[string][]int `json:"groups"`
+                Owner     *[]*Foo
+                Checksum  [4]byte
+                ID        uuid.UUID
+            }
+        "#;
+        let tokens = scanner::scan(valid_struct).expect("to be scanned correctly");
+
+        let parsed_result = parse(&tokens).expect("The struct should be parsed correctly");
+        assert_eq!(parsed_result.len(), 1)
+    }
 }
@@ -0,0 +1,347 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::ast::{DataType, Field, FieldName, FieldType, JsonTag, StructDeclaration, AST};
+use crate::interpreters::Options;
+
+/// A field that may end up in the marshaled object, along with what decides whether it wins
+/// over the other fields with the same JSON name.
+struct Candidate {
+    /// `None` for embedded types that aren't declared in the input, they can't conflict.
+    name: Option<String>,
+    depth: usize,
+    tagged: bool,
+    field: Field,
+    /// The position of the embedded struct of the resolved struct the field is promoted from.
+    /// The embedded struct itself has one too, at depth 0, when it may be flattened.
+    origin: Option<usize>,
+}
+
+/// Picks the fields of every struct the way `json.Marshal` does: unexported fields are
+/// dropped, the fields of embedded structs declared in the input are inlined, and fields
+/// with the same JSON name are settled by depth and tags. Embedded types that aren't declared
+/// in the input are kept as they are. With `flatten_embedded_structs`, so are the declared ones
+/// whose fields all make it to the struct, which then marshals them the same way they would.
+pub fn resolve(ast: Vec<AST>, options: &Options, flatten_embedded_structs: bool) -> Vec<AST> {
+    let definitions = ast
+        .iter()
+        .filter_map(|item| match item {
+            AST::Definition(declaration) | AST::Alias(declaration) => {
+                Some(declaration.name.clone())
+            }
+            _ => None,
+        })
+        .collect();
+    let text_keys = ast
+        .iter()
+        .filter_map(|item| match item {
+            AST::Declaration(declaration) => Some(declaration.name.clone()),
+            AST::Definition(declaration) | AST::Alias(declaration) => {
+                match declaration.definition {
+                    FieldType::Named(
+                        DataType::String
+                        | DataType::Integer { .. }
+                        | DataType::LargeNumber { .. }
+                        | DataType::Byte
+                        | DataType::Custom(_),
+                    ) => None,
+                    _ => Some(declaration.name.clone()),
+                }
+            }
+            _ => None,
+        })
+        .collect();
+    let structs = ast
+        .iter()
+        .filter_map(|item| match item {
+            AST::Declaration(declaration) => {
+                Some((declaration.name.clone(), (**declaration).clone()))
+            }
+            _ => None,
+        })
+        .collect();
+    let resolver = Resolver {
+        structs,
+        definitions,
+        text_keys,
+        options,
+        flatten_embedded_structs,
+    };
+    ast.into_iter()
+        .map(|item| match item {
+            AST::Declaration(mut declaration) => {
+                declaration.body = resolver.resolve_struct(&declaration.name, &declaration.body);
+                AST::Declaration(declaration)
+            }
+            AST::Definition(mut declaration) => {
+                declaration.definition = resolver.resolve_field_type(declaration.definition);
+                AST::Definition(declaration)
+            }
+            AST::Alias(mut declaration) => {
+                declaration.definition = resolver.resolve_field_type(declaration.definition);
+                AST::Alias(declaration)
+            }
+            item => item,
+        })
+        .collect()
+}
+
+struct Resolver<'o> {
+    structs: HashMap<String, StructDeclaration>,
+    /// The other declared types, embedding them adds a field named after the type.
+    definitions: HashSet<String>,
+    /// The declared types that aren't strings or integers, encoding/json only accepts them as
+    /// map keys if they are `encoding.TextMarshaler`s.
+    text_keys: HashSet<String>,
+    options: &'o Options,
+    /// Whether the interpreter flattens the embedded structs itself.
+    flatten_embedded_structs: bool,
+}
+
+impl Resolver<'_> {
+    fn resolve_struct(&self, name: &str, body: &[Field]) -> Vec<Field> {
+        let mut candidates = Vec::new();
+        let mut path = HashSet::from([name.to_string()]);
+        self.collect_candidates(body, 0, None, &mut path, &mut candidates);
+
+        let dominant = dominant_fields(&candidates, 0..candidates.len());
+        // An embedded struct marshals the fields that win among its own, it can only be
+        // flattened when none of them is shadowed or ambiguous here.
+        let flattened: HashSet<usize> = candidates
+            .iter()
+            .filter(|candidate| candidate.depth == 0)
+            .filter_map(|candidate| candidate.origin)
+            .filter(|&origin| {
+                let promoted = (0..candidates.len()).filter(|&index| {
+                    candidates[index].depth > 0 && candidates[index].origin == Some(origin)
+                });
+                dominant_fields(&candidates, promoted).is_subset(&dominant)
+            })
+            .collect();
+
+        candidates
+            .iter()
+            .enumerate()
+            .filter(|(index, candidate)| match candidate.origin {
+                Some(origin) if flattened.contains(&origin) => candidate.depth == 0,
+                Some(_) if candidate.depth == 0 => false,
+                _ => candidate.name.is_none() || dominant.contains(index),
+            })
+            .map(|(_, candidate)| self.resolve_field(candidate.field.clone()))
+            .collect()
+    }
+
+    fn collect_candidates(
+        &self,
+        body: &[Field],
+        depth: usize,
+        origin: Option<usize>,
+        path: &mut HashSet<String>,
+        candidates: &mut Vec<Candidate>,
+    ) {
+        for (position, field) in body.iter().enumerate() {
+            let (field_name, field_type, json_tag) = match field_parts(field) {
+                Some(parts) => parts,
+                None => continue,
+            };
+            if json_tag.skip {
+                continue;
+            }
+            if matches!(field_type, FieldType::Named(DataType::Embedded)) {
+                match (json_tag.name, self.structs.get(field_name)) {
+                    (None, Some(embedded)) => {
+                        // Go ignores the embedded struct altogether when it embeds itself.
+                        if path.insert(field_name.to_string()) {
+                            let origin = origin.or(Some(position));
+                            if depth == 0 && self.flatten_embedded_structs {
+                                candidates.push(Candidate {
+                                    name: None,
+                                    depth,
+                                    tagged: false,
+                                    field: field.clone(),
+                                    origin,
+                                })
+                            }
+                            let body = &embedded.body;
+                            self.collect_candidates(body, depth + 1, origin, path, candidates);
+                            path.remove(field_name);
+                        }
+                    }
+                    // Left for the interpreters to extend, there's nothing to inline.
+                    (None, None) if !self.definitions.contains(field_name) => {
+                        candidates.push(Candidate {
+                            name: None,
+                            depth,
+                            tagged: false,
+                            field: field.clone(),
+                            origin,
+                        })
+                    }
+                    // Embedded non-struct types and tagged embedded types are regular fields.
+                    (name, _) => {
+                        // Unlike structs, unexported embedded types don't promote anything.
+                        if name.is_none()
+                            && !is_exported(field_name)
+                            && !self.options.keep_unexported_fields
+                        {
+                            continue;
+                        }
+                        candidates.push(Candidate {
+                            tagged: name.is_some(),
+                            name: Some(name.unwrap_or_else(|| unqualified(field_name).to_string())),
+                            depth,
+                            field: name_embedded_field(field.clone()),
+                            origin,
+                        })
+                    }
+                }
+                continue;
+            }
+            if !is_exported(field_name) && !self.options.keep_unexported_fields {
+                continue;
+            }
+            candidates.push(Candidate {
+                tagged: json_tag.name.is_some(),
+                name: Some(json_tag.name.unwrap_or_else(|| field_name.to_string())),
+                depth,
+                field: field.clone(),
+                origin,
+            })
+        }
+    }
+
+    fn resolve_field(&self, field: Field) -> Field {
+        match field {
+            Field::Plain(name, field_type) => {
+                Field::Plain(name, self.resolve_field_type(field_type))
+            }
+            Field::WithTags(name, field_type, tags) => {
+                Field::WithTags(name, self.resolve_field_type(field_type), tags)
+            }
+            Field::Documented(doc, field) => {
+                Field::Documented(doc, Box::new(self.resolve_field(*field)))
+            }
+            Field::Blank => Field::Blank,
+        }
+    }
+
+    /// Anonymous structs follow the same rules as the declared ones.
+    fn resolve_field_type(&self, field_type: FieldType) -> FieldType {
+        match field_type {
+            FieldType::Struct(mut declaration) => {
+                declaration.body = self.resolve_struct(&declaration.name, &declaration.body);
+                FieldType::Struct(declaration)
+            }
+            FieldType::Slice(element_type) => {
+                FieldType::Slice(Box::new(self.resolve_field_type(*element_type)))
+            }
+            FieldType::Array(length, element_type) => {
+                FieldType::Array(length, Box::new(self.resolve_field_type(*element_type)))
+            }
+            FieldType::Pointer(pointee) => {
+                FieldType::Pointer(Box::new(self.resolve_field_type(*pointee)))
+            }
+            FieldType::Map(key_type, value_type) => FieldType::Map(
+                Box::new(self.resolve_key_type(*key_type)),
+                Box::new(self.resolve_field_type(*value_type)),
+            ),
+            field_type => field_type,
+        }
+    }
+
+    /// Text marshalers are written as strings when they're map keys.
+    fn resolve_key_type(&self, key_type: FieldType) -> FieldType {
+        match key_type {
+            FieldType::Named(DataType::Custom(name)) if self.text_keys.contains(&name) => {
+                FieldType::Named(DataType::String)
+            }
+            key_type => key_type,
+        }
+    }
+}
+
+/// The fields that win among `indices`, one per JSON name at most.
+fn dominant_fields(
+    candidates: &[Candidate],
+    indices: impl Iterator<Item = usize>,
+) -> HashSet<usize> {
+    let mut by_name: HashMap<&str, Vec<usize>> = HashMap::new();
+    for index in indices {
+        if let Some(name) = &candidates[index].name {
+            by_name.entry(name).or_default().push(index);
+        }
+    }
+    by_name
+        .values()
+        .filter_map(|indices| dominant_field(candidates, indices))
+        .collect()
+}
+
+/// The field with the shallowest depth wins, ties are broken by a JSON tag and any remaining
+/// tie drops every one of them.
+fn dominant_field(candidates: &[Candidate], indices: &[usize]) -> Option<usize> {
+    let depth = indices.iter().map(|&index| candidates[index].depth).min()?;
+    let shallowest: Vec<usize> = indices
+        .iter()
+        .copied()
+        .filter(|&index| candidates[index].depth == depth)
+        .collect();
+    if let [index] = shallowest[..] {
+        return Some(index);
+    }
+    let tagged: Vec<usize> = shallowest
+        .into_iter()
+        .filter(|&index| candidates[index].tagged)
+        .collect();
+    match tagged[..] {
+        [index] => Some(index),
+        _ => None,
+    }
+}
+
+fn field_parts(field: &Field) -> Option<(&str, &FieldType, JsonTag)> {
+    match field {
+        Field::Plain(name, field_type) => Some((&name.0, field_type, JsonTag::default())),
+        Field::WithTags(name, field_type, tags) => {
+            Some((&name.0, field_type, JsonTag::from_tags(tags)))
+        }
+        Field::Documented(_, field) => field_parts(field),
+        Field::Blank => None,
+    }
+}
+
+/// Turns an embedded field into a regular field named after its type, `pkg.Name` becomes `Name`.
+fn name_embedded_field(field: Field) -> Field {
+    let embedded_type = |name: &FieldName| {
+        let field_type = match name.0.split_once('.') {
+            Some((package, type_name)) => {
+                FieldType::Qualified(package.to_string(), type_name.to_string())
+            }
+            None => FieldType::Named(DataType::Custom(name.0.clone())),
+        };
+        (FieldName(unqualified(&name.0).to_string()), field_type)
+    };
+    match field {
+        Field::Plain(name, _) => {
+            let (name, field_type) = embedded_type(&name);
+            Field::Plain(name, field_type)
+        }
+        Field::WithTags(name, _, tags) => {
+            let (name, field_type) = embedded_type(&name);
+            Field::WithTags(name, field_type, tags)
+        }
+        Field::Documented(doc, field) => {
+            Field::Documented(doc, Box::new(name_embedded_field(*field)))
+        }
+        Field::Blank => Field::Blank,
+    }
+}
+
+fn unqualified(name: &str) -> &str {
+    name.rsplit('.').next().unwrap_or(name)
+}
+
+/// Go exports the identifiers starting with an upper case letter, `pkg.Name` is exported by
+/// `Name`.
+fn is_exported(name: &str) -> bool {
+    unqualified(name).starts_with(|c: char| c.is_uppercase())
+}
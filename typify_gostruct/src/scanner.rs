@@ -7,17 +7,34 @@ pub enum Token {
     LeftBrace,
     RightBrace,
     Colon,
+    Comma,
+    Equal,
+    Tilde,
+    Pipe,
     Identifier(String),
     StringLiteral(String),
+    RawStringLiteral(String),
+    RuneLiteral,
+    Comment(String),
     Whitespace,
-    Graveaccent,
     NextLine,
     LeftBracket,
     RightBracket,
+    LeftParen,
+    RightParen,
     Pointer,
+    Arrow,
     // Keywords
+    Package,
+    Import,
     Type,
     Struct,
+    Interface,
+    Map,
+    Const,
+    Var,
+    Func,
+    Chan,
     DataType(DataType),
 }
 
@@ -51,9 +68,12 @@ pub struct TokenWithContext {
     pub position: Position,
 }
 
+// The position is only reported through `Debug`.
+#[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub enum ScannerError {
     MissingStringTerminator(Position),
+    MissingCommentTerminator(Position),
 }
 
 struct Scanner<'a> {
@@ -63,11 +83,11 @@ struct Scanner<'a> {
 }
 
 fn is_digit(c: char) -> bool {
-    ('0'..='9').contains(&c)
+    c.is_ascii_digit()
 }
 
 fn is_alpha(c: char) -> bool {
-    ('a'..='z').contains(&c) || ('A'..='Z').contains(&c) || c == '.' || c == '-'
+    c.is_alphabetic() || c == '_' || c == '.' || c == '-'
 }
 
 fn is_alphanumeric(c: char) -> bool {
@@ -82,12 +102,16 @@ fn is_whitespace(c: char) -> bool {
     matches!(c, ' ' | '\r' | '\t')
 }
 
+fn integer(bits: u8, signed: bool) -> DataType {
+    DataType::Integer { bits, signed }
+}
+
 impl<'a> Scanner<'a> {
-    fn initialize(source: &'a str) -> Scanner {
+    fn initialize(source: &'a str) -> Scanner<'a> {
         Scanner {
             current_position: Position::initial(),
             current_lexeme: "".into(),
-            source: source.chars().into_iter().peekable(),
+            source: source.chars().peekable(),
         }
     }
 
@@ -125,11 +149,23 @@ impl<'a> Scanner<'a> {
             self.advance();
         }
     }
-    fn string(&mut self) -> Result<Token, ScannerError> {
-        self.advance_while(&|c| c != '"' && c != '\n');
-        if !self.advance_if_match('"') {
+    /// Advances up to the closing `terminator` of a quoted literal, skipping escaped characters.
+    fn quoted(&mut self, terminator: char) -> Result<(), ScannerError> {
+        loop {
+            self.advance_while(&|c| c != terminator && c != '\\' && c != '\n');
+            if !self.advance_if_match('\\') {
+                break;
+            }
+            let _ = self.advance();
+        }
+        if !self.advance_if_match(terminator) {
             return Err(ScannerError::MissingStringTerminator(self.current_position));
         }
+        Ok(())
+    }
+
+    fn string(&mut self) -> Result<Token, ScannerError> {
+        self.quoted('"')?;
         let literal_length = self.current_lexeme.len() - 2;
         let literal: String = self
             .current_lexeme
@@ -141,17 +177,76 @@ impl<'a> Scanner<'a> {
         Ok(Token::StringLiteral(literal))
     }
 
+    fn raw_string(&mut self) -> Result<Token, ScannerError> {
+        self.advance_while(&|c| c != '`');
+        if !self.advance_if_match('`') {
+            return Err(ScannerError::MissingStringTerminator(self.current_position));
+        }
+        let literal = &self.current_lexeme[1..self.current_lexeme.len() - 1];
+        Ok(Token::RawStringLiteral(literal.to_string()))
+    }
+
+    fn rune(&mut self) -> Result<Token, ScannerError> {
+        self.quoted('\'')?;
+        Ok(Token::RuneLiteral)
+    }
+
+    fn line_comment(&mut self) -> Token {
+        self.advance_while(&|c| c != '\n');
+        let text = self.current_lexeme.trim_start_matches('/').trim();
+        Token::Comment(text.to_string())
+    }
+
+    fn block_comment(&mut self) -> Result<Token, ScannerError> {
+        loop {
+            match self.advance() {
+                Some('*') if self.advance_if_match('/') => break,
+                Some(_) => {}
+                None => {
+                    return Err(ScannerError::MissingCommentTerminator(
+                        self.current_position,
+                    ))
+                }
+            }
+        }
+        let content = &self.current_lexeme[2..self.current_lexeme.len() - 2];
+        let lines: Vec<&str> = content
+            .lines()
+            .map(|line| {
+                let line = line.trim();
+                line.strip_prefix('*').unwrap_or(line).trim()
+            })
+            .collect();
+        Ok(Token::Comment(lines.join("\n").trim().to_string()))
+    }
+
     fn identifier(&mut self) -> Token {
         self.advance_while(&is_alphanumeric);
         match self.current_lexeme.as_ref() {
+            "package" => Token::Package,
+            "import" => Token::Import,
             "type" => Token::Type,
             "struct" => Token::Struct,
+            "interface" => Token::Interface,
+            "map" => Token::Map,
+            "const" => Token::Const,
+            "var" => Token::Var,
+            "func" => Token::Func,
+            "chan" => Token::Chan,
             // data types
-            "int64" => Token::DataType(DataType::Number),
-            "float64" => Token::DataType(DataType::Number),
+            "int" => Token::DataType(integer(64, true)),
+            "int8" => Token::DataType(integer(8, true)),
+            "int16" => Token::DataType(integer(16, true)),
+            "int32" | "rune" => Token::DataType(integer(32, true)),
+            "uint" | "uintptr" => Token::DataType(integer(64, false)),
+            "uint16" => Token::DataType(integer(16, false)),
+            "uint32" => Token::DataType(integer(32, false)),
+            "float32" | "float64" => Token::DataType(DataType::Float),
+            "byte" | "uint8" => Token::DataType(DataType::Byte),
+            "int64" => Token::DataType(DataType::LargeNumber { signed: true }),
+            "uint64" => Token::DataType(DataType::LargeNumber { signed: false }),
+            "complex64" | "complex128" => Token::DataType(DataType::Complex),
             "string" => Token::DataType(DataType::String),
-            "int" => Token::DataType(DataType::Number),
-            "time.Time" => Token::DataType(DataType::String),
             "bool" => Token::DataType(DataType::Boolean),
             identifier => Token::Identifier(identifier.into()),
         }
@@ -169,22 +264,31 @@ impl<'a> Scanner<'a> {
         let initial_position = self.current_position;
         self.current_lexeme.clear();
 
-        let next_char = match self.advance() {
-            Some(c) => c,
-            None => return None,
-        };
+        let next_char = self.advance()?;
 
         let result = match next_char {
             ':' => Ok(Token::Colon),
+            ',' => Ok(Token::Comma),
+            '=' => Ok(Token::Equal),
+            '~' => Ok(Token::Tilde),
+            '|' => Ok(Token::Pipe),
             '{' => Ok(Token::LeftBrace),
             '}' => Ok(Token::RightBrace),
-            '`' => Ok(Token::Graveaccent),
+            '`' => self.raw_string(),
+            '\'' => self.rune(),
             '[' => Ok(Token::LeftBracket),
             ']' => Ok(Token::RightBracket),
+            '(' => Ok(Token::LeftParen),
+            ')' => Ok(Token::RightParen),
             '*' => Ok(Token::Pointer),
+            '<' if self.advance_if_match('-') => Ok(Token::Arrow),
+            // Go's own lexer treats line breaks as implicit semicolons.
+            ';' => Ok(Token::NextLine),
             c if is_nextline(c) => Ok(Token::NextLine),
             c if is_whitespace(c) => Ok(Token::Whitespace),
             '"' => self.string(),
+            '/' if self.advance_if_match('/') => Ok(self.line_comment()),
+            '/' if self.advance_if_match('*') => self.block_comment(),
             _ => Ok(self.identifier()),
         };
         Some(result.map(|token| self.add_context(token, initial_position)))
@@ -234,7 +338,6 @@ pub fn scan(input: impl Input) -> Result<Vec<TokenWithContext>, Vec<String>> {
             Ok(token_with_context) => {
                 match token_with_context.token {
                     Token::Whitespace => {}
-                    Token::Pointer => {}
                     _ => tokens.push(token_with_context),
                 };
             }
@@ -0,0 +1,124 @@
+use std::{env, fs, path::Path, process::Command};
+
+use typify_gostruct::{FlowOptions, ObjectStyle, Options, Source};
+
+/// Transforms every `.go` file of `tests/golden` to `target` and compares the output with the
+/// file of the same name and `extension`. Set `UPDATE_GOLDEN=1` to rewrite the expected files.
+fn check_golden_files(target: &str, extension: &str, options: Options) {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden");
+    let update = env::var_os("UPDATE_GOLDEN").is_some();
+    let mut go_files: Vec<_> = fs::read_dir(&directory)
+        .expect("the golden directory should exist")
+        .map(|entry| entry.expect("the golden file should be readable").path())
+        .filter(|path| path.extension().is_some_and(|e| e == "go"))
+        .collect();
+    go_files.sort();
+    assert!(!go_files.is_empty(), "there should be golden files");
+
+    let mut mismatches = Vec::new();
+    for go_file in go_files {
+        let input = fs::read_to_string(&go_file).expect("the go file should be readable");
+        let output = Source::new(input.as_str())
+            .with_options(options.clone())
+            .transform_to(target)
+            .unwrap_or_else(|err| panic!("{} should transform: {:?}", go_file.display(), err));
+        let expected_file = go_file.with_extension(extension);
+        if update {
+            fs::write(&expected_file, &output).expect("the expected file should be writable");
+            continue;
+        }
+        let expected = fs::read_to_string(&expected_file).unwrap_or_default();
+        if output != expected {
+            mismatches.push(format!(
+                "{}\n--- expected\n{}\n--- generated\n{}",
+                expected_file.display(),
+                expected,
+                output
+            ));
+        }
+    }
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+}
+
+#[test]
+fn typescript_output_matches_golden_files() {
+    check_golden_files("typescript", "ts", Options::default())
+}
+
+#[test]
+fn flow_output_matches_golden_files() {
+    check_golden_files("flow", "js", Options::default())
+}
+
+#[test]
+fn json_schema_output_matches_golden_files() {
+    check_golden_files("jsonschema", "schema.json", Options::default())
+}
+
+#[test]
+fn zod_output_matches_golden_files() {
+    check_golden_files("zod", "zod.ts", Options::default())
+}
+
+#[test]
+fn io_ts_output_matches_golden_files() {
+    check_golden_files("io-ts", "io-ts.ts", Options::default())
+}
+
+#[test]
+fn rust_output_matches_golden_files() {
+    check_golden_files("rust", "rs", Options::default())
+}
+
+#[test]
+fn exact_read_only_flow_output_matches_golden_files() {
+    let options = Options {
+        flow: FlowOptions {
+            objects: ObjectStyle::Exact,
+            readonly: true,
+            readonly_arrays: true,
+        },
+        ..Default::default()
+    };
+    check_golden_files("flow", "exact.js", options)
+}
+
+/// Needs node & network access for `npx` to fetch typescript, hence ignored by default.
+#[test]
+#[ignore = "runs tsc through npx, use `cargo test --test golden -- --ignored`"]
+fn typescript_golden_files_type_check() {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden");
+    let mut typescript_files: Vec<_> = fs::read_dir(&directory)
+        .expect("the golden directory should exist")
+        .map(|entry| entry.expect("the golden file should be readable").path())
+        .filter(|path| path.extension().is_some_and(|e| e == "ts"))
+        // `basic.zod.ts` & co need their validation library installed.
+        .filter(|path| {
+            path.file_stem()
+                .is_some_and(|s| !s.to_string_lossy().contains('.'))
+        })
+        .collect();
+    typescript_files.sort();
+    let status = Command::new("npx")
+        .args(["--yes", "-p", "typescript", "tsc", "--strict", "--noEmit"])
+        .args(&typescript_files)
+        .status()
+        .expect("npx should be installed");
+    assert!(
+        status.success(),
+        "the typescript golden files should type-check"
+    )
+}
+
+/// Needs node & network access for `npx` to fetch flow-bin, hence ignored by default.
+#[test]
+#[ignore = "runs flow-bin through npx, use `cargo test --test golden -- --ignored`"]
+fn flow_golden_files_type_check() {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden");
+    let status = Command::new("npx")
+        .args(["--yes", "flow-bin", "check"])
+        .arg(&directory)
+        .status()
+        .expect("npx should be installed");
+    assert!(status.success(), "the flow golden files should type-check")
+}
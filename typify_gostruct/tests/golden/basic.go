@@ -0,0 +1,12 @@
+type Region struct {
+	Country string `json:"country" binding:"required"`
+	State   string
+	Zip     int    `json:"zip,omitempty"`
+	Ignored string `json:"-"`
+	Dash    string `json:"-,"`
+	Code    int64  `json:"code,string"`
+	Active  bool
+	Ratio   float32
+}
+
+type Empty struct{}
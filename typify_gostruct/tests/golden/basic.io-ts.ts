@@ -0,0 +1,19 @@
+import * as t from "io-ts";
+
+export const Region = t.intersection([
+  t.type({
+    country: t.string,
+    State: t.string,
+    "-": t.string,
+    code: t.string,
+    Active: t.boolean,
+    Ratio: t.number,
+  }),
+  t.partial({
+    zip: t.number,
+  }),
+]);
+export type Region = t.TypeOf<typeof Region>;
+
+export const Empty = t.type({});
+export type Empty = t.TypeOf<typeof Empty>;
@@ -0,0 +1,13 @@
+// @flow
+
+export type Region = {
+  country: string,
+  State: string,
+  zip?: number,
+  "-": string,
+  code: string,
+  Active: boolean,
+  Ratio: number,
+};
+
+export type Empty = {};
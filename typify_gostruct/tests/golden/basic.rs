@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct Region {
+    pub country: String,
+    #[serde(rename = "State")]
+    pub state: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zip: Option<i64>,
+    #[serde(rename = "-")]
+    pub dash: String,
+    pub code: String,
+    #[serde(rename = "Active")]
+    pub active: bool,
+    #[serde(rename = "Ratio")]
+    pub ratio: f64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Empty {}
@@ -0,0 +1,11 @@
+export interface Region {
+  country: string;
+  State: string;
+  zip?: number;
+  "-": string;
+  code: string;
+  Active: boolean;
+  Ratio: number;
+}
+
+export interface Empty {}
@@ -0,0 +1,15 @@
+import { z } from "zod";
+
+export const RegionSchema = z.object({
+  country: z.string(),
+  State: z.string(),
+  zip: z.number().int().optional(),
+  "-": z.string(),
+  code: z.string(),
+  Active: z.boolean(),
+  Ratio: z.number(),
+});
+export type Region = z.infer<typeof RegionSchema>;
+
+export const EmptySchema = z.object({});
+export type Empty = z.infer<typeof EmptySchema>;
@@ -0,0 +1,25 @@
+// @flow
+
+export type Timestamps = {|
+  +created_at: string,
+  +updated_at: string,
+|};
+
+export type Named = {|
+  +Name: string,
+  +Label: string,
+|};
+
+export type Labeled = {|
+  +Label: string,
+|};
+
+export type Account = {|
+  +created_at: string,
+  +updated_at: string,
+  +Name: string,
+  +id: string,
+  +Balance: string,
+  +Nickname: ?string,
+  +Extra: mixed,
+|};
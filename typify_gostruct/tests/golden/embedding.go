@@ -0,0 +1,24 @@
+type Timestamps struct {
+	CreatedAt time.Time `json:"created_at"`
+	UpdatedAt time.Time `json:"updated_at"`
+}
+
+type Named struct {
+	Name  string
+	Label string
+}
+
+type Labeled struct {
+	Label string
+}
+
+type Account struct {
+	Timestamps
+	Named
+	Labeled
+	ID       uuid.UUID `json:"id"`
+	Balance  decimal.Decimal
+	Nickname sql.NullString
+	Extra    json.RawMessage
+	internal string
+}
@@ -0,0 +1,29 @@
+import * as t from "io-ts";
+
+export const Timestamps = t.type({
+  created_at: t.string,
+  updated_at: t.string,
+});
+export type Timestamps = t.TypeOf<typeof Timestamps>;
+
+export const Named = t.type({
+  Name: t.string,
+  Label: t.string,
+});
+export type Named = t.TypeOf<typeof Named>;
+
+export const Labeled = t.type({
+  Label: t.string,
+});
+export type Labeled = t.TypeOf<typeof Labeled>;
+
+export const Account = t.type({
+  created_at: t.string,
+  updated_at: t.string,
+  Name: t.string,
+  id: t.string,
+  Balance: t.string,
+  Nickname: t.union([t.string, t.null]),
+  Extra: t.unknown,
+});
+export type Account = t.TypeOf<typeof Account>;
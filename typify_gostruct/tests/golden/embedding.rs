@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct Timestamps {
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Named {
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Label")]
+    pub label: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Labeled {
+    #[serde(rename = "Label")]
+    pub label: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Account {
+    #[serde(flatten)]
+    pub timestamps: Timestamps,
+    #[serde(rename = "Name")]
+    pub name: String,
+    pub id: String,
+    #[serde(rename = "Balance")]
+    pub balance: String,
+    #[serde(rename = "Nickname")]
+    pub nickname: Option<String>,
+    #[serde(rename = "Extra")]
+    pub extra: serde_json::Value,
+}
@@ -0,0 +1,23 @@
+export interface Timestamps {
+  created_at: string;
+  updated_at: string;
+}
+
+export interface Named {
+  Name: string;
+  Label: string;
+}
+
+export interface Labeled {
+  Label: string;
+}
+
+export interface Account {
+  created_at: string;
+  updated_at: string;
+  Name: string;
+  id: string;
+  Balance: string;
+  Nickname: string | null;
+  Extra: unknown;
+}
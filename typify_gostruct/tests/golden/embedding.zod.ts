@@ -0,0 +1,29 @@
+import { z } from "zod";
+
+export const TimestampsSchema = z.object({
+  created_at: z.string().datetime({ offset: true }),
+  updated_at: z.string().datetime({ offset: true }),
+});
+export type Timestamps = z.infer<typeof TimestampsSchema>;
+
+export const NamedSchema = z.object({
+  Name: z.string(),
+  Label: z.string(),
+});
+export type Named = z.infer<typeof NamedSchema>;
+
+export const LabeledSchema = z.object({
+  Label: z.string(),
+});
+export type Labeled = z.infer<typeof LabeledSchema>;
+
+export const AccountSchema = z.object({
+  created_at: z.string().datetime({ offset: true }),
+  updated_at: z.string().datetime({ offset: true }),
+  Name: z.string(),
+  id: z.string().uuid(),
+  Balance: z.string(),
+  Nickname: z.string().nullable(),
+  Extra: z.unknown(),
+});
+export type Account = z.infer<typeof AccountSchema>;
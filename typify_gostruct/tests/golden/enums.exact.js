@@ -0,0 +1,6 @@
+// @flow
+
+export type Color = 0 | 1 | 2;
+
+/** Role of a user. */
+export type Role = "admin" | "user";
@@ -0,0 +1,18 @@
+type Color int
+
+const (
+	Red Color = iota
+	Green
+	Blue
+)
+
+// Role of a user.
+type Role string
+
+const (
+	// RoleAdmin can do anything.
+	RoleAdmin Role = "admin"
+	RoleUser  Role = "user"
+)
+
+const MaxRetries = 3
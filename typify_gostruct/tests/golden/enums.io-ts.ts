@@ -0,0 +1,8 @@
+import * as t from "io-ts";
+
+export const Color = t.union([t.literal(0), t.literal(1), t.literal(2)]);
+export type Color = t.TypeOf<typeof Color>;
+
+/** Role of a user. */
+export const Role = t.union([t.literal("admin"), t.literal("user")]);
+export type Role = t.TypeOf<typeof Role>;
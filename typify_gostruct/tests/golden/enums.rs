@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+pub type Color = i64;
+
+/// Role of a user.
+#[derive(Serialize, Deserialize)]
+pub enum Role {
+    #[serde(rename = "admin")]
+    RoleAdmin,
+    #[serde(rename = "user")]
+    RoleUser,
+}
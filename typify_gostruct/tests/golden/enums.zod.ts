@@ -0,0 +1,8 @@
+import { z } from "zod";
+
+export const ColorSchema = z.union([z.literal(0), z.literal(1), z.literal(2)]);
+export type Color = z.infer<typeof ColorSchema>;
+
+/** Role of a user. */
+export const RoleSchema = z.enum(["admin", "user"]);
+export type Role = z.infer<typeof RoleSchema>;
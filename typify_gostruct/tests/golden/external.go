@@ -0,0 +1,16 @@
+// Point is written as text when it's a map key.
+type Point struct {
+	X, Y int
+}
+
+type Flag bool
+
+type Inventory struct {
+	gorm.Model
+	Owner    auth.User `json:"owner"`
+	Counts   map[Point]int
+	Enabled  map[Flag]string
+	Sessions map[uuid.UUID]time.Time
+	Grants   map[auth.Role][]string
+	Totals   map[int64]float64
+}
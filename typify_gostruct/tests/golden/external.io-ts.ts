@@ -0,0 +1,21 @@
+import * as t from "io-ts";
+
+/** Point is written as text when it's a map key. */
+export const Point = t.type({
+  X: t.number,
+  Y: t.number,
+});
+export type Point = t.TypeOf<typeof Point>;
+
+export const Flag = t.boolean;
+export type Flag = t.TypeOf<typeof Flag>;
+
+export const Inventory = t.type({
+  owner: t.unknown,
+  Counts: t.record(t.string, t.number),
+  Enabled: t.record(t.string, t.string),
+  Sessions: t.record(t.string, t.string),
+  Grants: t.record(t.string, t.array(t.string)),
+  Totals: t.record(t.string, t.number),
+});
+export type Inventory = t.TypeOf<typeof Inventory>;
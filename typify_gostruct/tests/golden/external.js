@@ -0,0 +1,18 @@
+// @flow
+
+/** Point is written as text when it's a map key. */
+export type Point = {
+  X: number,
+  Y: number,
+};
+
+export type Flag = boolean;
+
+export type Inventory = {
+  owner: mixed,
+  Counts: { [key: string]: number },
+  Enabled: { [key: string]: string },
+  Sessions: { [key: string]: string },
+  Grants: { [key: mixed]: string[] },
+  Totals: { [key: number]: number },
+};
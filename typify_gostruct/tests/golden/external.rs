@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Point is written as text when it's a map key.
+#[derive(Serialize, Deserialize)]
+pub struct Point {
+    #[serde(rename = "X")]
+    pub x: i64,
+    #[serde(rename = "Y")]
+    pub y: i64,
+}
+
+pub type Flag = bool;
+
+#[derive(Serialize, Deserialize)]
+pub struct Inventory {
+    #[serde(flatten)]
+    pub model: gorm::Model,
+    pub owner: auth::User,
+    #[serde(rename = "Counts")]
+    pub counts: HashMap<String, i64>,
+    #[serde(rename = "Enabled")]
+    pub enabled: HashMap<String, String>,
+    #[serde(rename = "Sessions")]
+    pub sessions: HashMap<String, String>,
+    #[serde(rename = "Grants")]
+    pub grants: HashMap<auth::Role, Vec<String>>,
+    #[serde(rename = "Totals")]
+    pub totals: HashMap<i64, f64>,
+}
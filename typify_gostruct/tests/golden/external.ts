@@ -0,0 +1,16 @@
+/** Point is written as text when it's a map key. */
+export interface Point {
+  X: number;
+  Y: number;
+}
+
+export type Flag = boolean;
+
+export interface Inventory {
+  owner: unknown;
+  Counts: Record<string, number>;
+  Enabled: Record<string, string>;
+  Sessions: Record<string, string>;
+  Grants: Record<string, string[]>;
+  Totals: Record<number, number>;
+}
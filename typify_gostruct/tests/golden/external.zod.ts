@@ -0,0 +1,21 @@
+import { z } from "zod";
+
+/** Point is written as text when it's a map key. */
+export const PointSchema = z.object({
+  X: z.number().int(),
+  Y: z.number().int(),
+});
+export type Point = z.infer<typeof PointSchema>;
+
+export const FlagSchema = z.boolean();
+export type Flag = z.infer<typeof FlagSchema>;
+
+export const InventorySchema = z.object({
+  owner: z.unknown(),
+  Counts: z.record(z.string(), z.number().int()),
+  Enabled: z.record(z.string(), z.string()),
+  Sessions: z.record(z.string(), z.string().datetime({ offset: true })),
+  Grants: z.record(z.string(), z.array(z.string())),
+  Totals: z.record(z.string(), z.number()),
+}).passthrough();
+export type Inventory = z.infer<typeof InventorySchema>;
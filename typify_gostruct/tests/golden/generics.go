@@ -0,0 +1,22 @@
+type Numeric interface {
+	~int | ~float64
+}
+
+type Page[T any] struct {
+	Items []T `json:"items"`
+	Next  *string
+}
+
+type Pair[K comparable, V ~int | ~float64] struct {
+	Key   K
+	Value V
+}
+
+type Region struct {
+	Name string
+}
+
+type Listing struct {
+	Regions Page[Region]
+	Counts  Pair[string, int]
+}
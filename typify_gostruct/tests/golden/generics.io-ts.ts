@@ -0,0 +1,33 @@
+import * as t from "io-ts";
+
+export const Numeric = t.unknown;
+export type Numeric = t.TypeOf<typeof Numeric>;
+
+export type Page<T> = {
+  items: T[];
+  Next: string | null;
+};
+export const Page = <T extends t.Mixed>(T: T) => t.type({
+  items: t.array(T),
+  Next: t.union([t.string, t.null]),
+});
+
+export type Pair<K, V extends number> = {
+  Key: K;
+  Value: V;
+};
+export const Pair = <K extends t.Mixed, V extends t.Mixed>(K: K, V: V) => t.type({
+  Key: K,
+  Value: V,
+});
+
+export const Region = t.type({
+  Name: t.string,
+});
+export type Region = t.TypeOf<typeof Region>;
+
+export const Listing = t.type({
+  Regions: Page(Region),
+  Counts: Pair(t.string, t.number),
+});
+export type Listing = t.TypeOf<typeof Listing>;
@@ -0,0 +1,22 @@
+// @flow
+
+export type Numeric = mixed;
+
+export type Page<T> = {
+  items: T[],
+  Next: ?string,
+};
+
+export type Pair<K, V: number> = {
+  Key: K,
+  Value: V,
+};
+
+export type Region = {
+  Name: string,
+};
+
+export type Listing = {
+  Regions: Page<Region>,
+  Counts: Pair<string, number>,
+};
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+pub type Numeric = serde_json::Value;
+
+#[derive(Serialize, Deserialize)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    #[serde(rename = "Next")]
+    pub next: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Pair<K, V> {
+    #[serde(rename = "Key")]
+    pub key: K,
+    #[serde(rename = "Value")]
+    pub value: V,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Region {
+    #[serde(rename = "Name")]
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Listing {
+    #[serde(rename = "Regions")]
+    pub regions: Page<Region>,
+    #[serde(rename = "Counts")]
+    pub counts: Pair<String, i64>,
+}
@@ -0,0 +1,20 @@
+export type Numeric = unknown;
+
+export interface Page<T> {
+  items: T[];
+  Next: string | null;
+}
+
+export interface Pair<K, V extends number> {
+  Key: K;
+  Value: V;
+}
+
+export interface Region {
+  Name: string;
+}
+
+export interface Listing {
+  Regions: Page<Region>;
+  Counts: Pair<string, number>;
+}
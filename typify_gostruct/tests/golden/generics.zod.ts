@@ -0,0 +1,33 @@
+import { z } from "zod";
+
+export const NumericSchema = z.unknown();
+export type Numeric = z.infer<typeof NumericSchema>;
+
+export type Page<T> = {
+  items: T[];
+  Next: string | null;
+};
+export const PageSchema = <T extends z.ZodTypeAny>(T: T) => z.object({
+  items: z.array(T),
+  Next: z.string().nullable(),
+});
+
+export type Pair<K, V extends number> = {
+  Key: K;
+  Value: V;
+};
+export const PairSchema = <K extends z.ZodTypeAny, V extends z.ZodTypeAny>(K: K, V: V) => z.object({
+  Key: K,
+  Value: V,
+});
+
+export const RegionSchema = z.object({
+  Name: z.string(),
+});
+export type Region = z.infer<typeof RegionSchema>;
+
+export const ListingSchema = z.object({
+  Regions: PageSchema(RegionSchema),
+  Counts: PairSchema(z.string(), z.number().int()),
+});
+export type Listing = z.infer<typeof ListingSchema>;
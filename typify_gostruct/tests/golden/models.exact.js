@@ -0,0 +1,39 @@
+// @flow
+
+/** Role of a user. */
+export type Role = "admin" | "user";
+
+export type Level = 1 | 2;
+
+/** Base holds the common fields. */
+export type Base = {|
+  +id: number,
+  +created_at: string,
+|};
+
+export type Page<T> = {|
+  +items: $ReadOnlyArray<T>,
+  +total: number,
+|};
+
+export type Empty = {||};
+
+export type IDs = $ReadOnlyArray<number>;
+
+/** User is an account. */
+export type User = {|
+  +id: number,
+  +created_at: string,
+  /** Name is shown. */
+  +name: string,
+  +email?: ?string,
+  +role: Role,
+  +tags: $ReadOnlyArray<string>,
+  +meta: { +[key: string]: mixed },
+  +"kebab-case": string,
+  +address: {|
+    +city: string,
+  |},
+  +friends: $ReadOnlyArray<?User>,
+  +scores: { +[key: string]: $ReadOnlyArray<number> },
+|};
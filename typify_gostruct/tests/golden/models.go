@@ -0,0 +1,53 @@
+package models
+
+import "time"
+
+// Role of a user.
+type Role string
+
+const (
+	RoleAdmin Role = "admin"
+	RoleUser  Role = "user"
+)
+
+type Level int
+
+const (
+	Low Level = iota + 1
+	High
+)
+
+// Base holds the common fields.
+type Base struct {
+	ID        int64     `json:"id"`
+	CreatedAt time.Time `json:"created_at"`
+}
+
+type Page[T any] struct {
+	Items []T `json:"items"`
+	Total int `json:"total"`
+}
+
+type Empty struct{}
+
+type IDs = []int64
+
+// User is an account.
+type User struct {
+	Base
+	// Name is shown.
+	Name     string            `json:"name"`
+	Email    *string           `json:"email,omitempty"`
+	Role     Role              `json:"role"`
+	Tags     []string          `json:"tags"`
+	Meta     map[string]any    `json:"meta"`
+	Kebab    string            `json:"kebab-case"`
+	Address  struct {
+		City string `json:"city"`
+	} `json:"address"`
+	Friends  []*User           `json:"friends"`
+	Scores   map[string][]int  `json:"scores"`
+	password string
+}
+
+func (u *User) String() string { return u.Name }
@@ -0,0 +1,70 @@
+import * as t from "io-ts";
+
+/** Role of a user. */
+export const Role = t.union([t.literal("admin"), t.literal("user")]);
+export type Role = t.TypeOf<typeof Role>;
+
+export const Level = t.union([t.literal(1), t.literal(2)]);
+export type Level = t.TypeOf<typeof Level>;
+
+/** Base holds the common fields. */
+export const Base = t.type({
+  id: t.number,
+  created_at: t.string,
+});
+export type Base = t.TypeOf<typeof Base>;
+
+export type Page<T> = {
+  items: T[];
+  total: number;
+};
+export const Page = <T extends t.Mixed>(T: T) => t.type({
+  items: t.array(T),
+  total: t.number,
+});
+
+export const Empty = t.type({});
+export type Empty = t.TypeOf<typeof Empty>;
+
+export const IDs = t.array(t.number);
+export type IDs = t.TypeOf<typeof IDs>;
+
+/** User is an account. */
+export type User = {
+  id: number;
+  created_at: string;
+  /** Name is shown. */
+  name: string;
+  email?: string | null;
+  role: Role;
+  tags: string[];
+  meta: Record<string, unknown>;
+  "kebab-case": string;
+  address: {
+    city: string;
+  };
+  friends: Array<User | null>;
+  scores: Record<string, number[]>;
+};
+export const User: t.Type<User> = t.recursion("User", () =>
+  t.intersection([
+    t.type({
+      id: t.number,
+      created_at: t.string,
+      /** Name is shown. */
+      name: t.string,
+      role: Role,
+      tags: t.array(t.string),
+      meta: t.record(t.string, t.unknown),
+      "kebab-case": t.string,
+      address: t.type({
+        city: t.string,
+      }),
+      friends: t.array(t.union([User, t.null])),
+      scores: t.record(t.string, t.array(t.number)),
+    }),
+    t.partial({
+      email: t.union([t.string, t.null]),
+    }),
+  ]),
+);
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Role of a user.
+#[derive(Serialize, Deserialize)]
+pub enum Role {
+    #[serde(rename = "admin")]
+    RoleAdmin,
+    #[serde(rename = "user")]
+    RoleUser,
+}
+
+pub type Level = i64;
+
+/// Base holds the common fields.
+#[derive(Serialize, Deserialize)]
+pub struct Base {
+    pub id: i64,
+    pub created_at: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: i64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Empty {}
+
+pub type IDs = Vec<i64>;
+
+/// User is an account.
+#[derive(Serialize, Deserialize)]
+pub struct User {
+    #[serde(flatten)]
+    pub base: Base,
+    /// Name is shown.
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    pub role: Role,
+    pub tags: Vec<String>,
+    pub meta: HashMap<String, serde_json::Value>,
+    #[serde(rename = "kebab-case")]
+    pub kebab: String,
+    pub address: UserAddress,
+    pub friends: Vec<Option<Box<User>>>,
+    pub scores: HashMap<String, Vec<i64>>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UserAddress {
+    pub city: String,
+}
@@ -0,0 +1,37 @@
+/** Role of a user. */
+export type Role = "admin" | "user";
+
+export type Level = 1 | 2;
+
+/** Base holds the common fields. */
+export interface Base {
+  id: number;
+  created_at: string;
+}
+
+export interface Page<T> {
+  items: T[];
+  total: number;
+}
+
+export interface Empty {}
+
+export type IDs = number[];
+
+/** User is an account. */
+export interface User {
+  id: number;
+  created_at: string;
+  /** Name is shown. */
+  name: string;
+  email?: string | null;
+  role: Role;
+  tags: string[];
+  meta: Record<string, unknown>;
+  "kebab-case": string;
+  address: {
+    city: string;
+  };
+  friends: Array<User | null>;
+  scores: Record<string, number[]>;
+}
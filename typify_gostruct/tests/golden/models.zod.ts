@@ -0,0 +1,66 @@
+import { z } from "zod";
+
+/** Role of a user. */
+export const RoleSchema = z.enum(["admin", "user"]);
+export type Role = z.infer<typeof RoleSchema>;
+
+export const LevelSchema = z.union([z.literal(1), z.literal(2)]);
+export type Level = z.infer<typeof LevelSchema>;
+
+/** Base holds the common fields. */
+export const BaseSchema = z.object({
+  id: z.number().int(),
+  created_at: z.string().datetime({ offset: true }),
+});
+export type Base = z.infer<typeof BaseSchema>;
+
+export type Page<T> = {
+  items: T[];
+  total: number;
+};
+export const PageSchema = <T extends z.ZodTypeAny>(T: T) => z.object({
+  items: z.array(T),
+  total: z.number().int(),
+});
+
+export const EmptySchema = z.object({});
+export type Empty = z.infer<typeof EmptySchema>;
+
+export const IDsSchema = z.array(z.number().int());
+export type IDs = z.infer<typeof IDsSchema>;
+
+/** User is an account. */
+export type User = {
+  id: number;
+  created_at: string;
+  /** Name is shown. */
+  name: string;
+  email?: string | null;
+  role: Role;
+  tags: string[];
+  meta: Record<string, unknown>;
+  "kebab-case": string;
+  address: {
+    city: string;
+  };
+  friends: Array<User | null>;
+  scores: Record<string, number[]>;
+};
+export const UserSchema: z.ZodType<User> = z.lazy(() =>
+  z.object({
+    id: z.number().int(),
+    created_at: z.string().datetime({ offset: true }),
+    /** Name is shown. */
+    name: z.string(),
+    email: z.string().nullable().optional(),
+    role: RoleSchema,
+    tags: z.array(z.string()),
+    meta: z.record(z.string(), z.unknown()),
+    "kebab-case": z.string(),
+    address: z.object({
+      city: z.string(),
+    }),
+    friends: z.array(UserSchema.nullable()),
+    scores: z.record(z.string(), z.array(z.number().int())),
+  }),
+);
@@ -0,0 +1,26 @@
+// @flow
+
+export type Status = string;
+
+export type IDs = $ReadOnlyArray<number>;
+
+export type Lookup = { +[key: string]: ?Region };
+
+export type Grid = $ReadOnlyArray<$ReadOnlyArray<number>>;
+
+export type Region = {|
+  +Name: string,
+  +Neighbors: $ReadOnlyArray<?Region>,
+  +Index: { +[key: number]: string },
+  +Matrix: $ReadOnlyArray<$ReadOnlyArray<number>>,
+  +Avatar: string,
+  +Settings: {|
+    +theme: string,
+    +Limits: {|
+      +Daily: number,
+    |},
+  |},
+  +Items: $ReadOnlyArray<{|
+    +id: string,
+  |}>,
+|};
@@ -0,0 +1,23 @@
+type (
+	Status string
+	IDs    = []int64
+	Lookup map[string]*Region
+	Grid   [3][3]int
+)
+
+type Region struct {
+	Name      string
+	Neighbors []*Region
+	Index     map[int]string
+	Matrix    [][]float64
+	Avatar    []byte
+	Settings  struct {
+		Theme string `json:"theme"`
+		Limits struct {
+			Daily int
+		}
+	}
+	Items []struct {
+		ID string `json:"id"`
+	}
+}
@@ -0,0 +1,48 @@
+import * as t from "io-ts";
+
+export const Status = t.string;
+export type Status = t.TypeOf<typeof Status>;
+
+export const IDs = t.array(t.number);
+export type IDs = t.TypeOf<typeof IDs>;
+
+export type Region = {
+  Name: string;
+  Neighbors: Array<Region | null>;
+  Index: Record<number, string>;
+  Matrix: number[][];
+  Avatar: string;
+  Settings: {
+    theme: string;
+    Limits: {
+      Daily: number;
+    };
+  };
+  Items: Array<{
+    id: string;
+  }>;
+};
+export const Region: t.Type<Region> = t.recursion("Region", () =>
+  t.type({
+    Name: t.string,
+    Neighbors: t.array(t.union([Region, t.null])),
+    Index: t.record(t.string, t.string),
+    Matrix: t.array(t.array(t.number)),
+    Avatar: t.string,
+    Settings: t.type({
+      theme: t.string,
+      Limits: t.type({
+        Daily: t.number,
+      }),
+    }),
+    Items: t.array(t.type({
+      id: t.string,
+    })),
+  }),
+);
+
+export const Lookup = t.record(t.string, t.union([Region, t.null]));
+export type Lookup = t.TypeOf<typeof Lookup>;
+
+export const Grid = t.array(t.array(t.number));
+export type Grid = t.TypeOf<typeof Grid>;
@@ -0,0 +1,26 @@
+// @flow
+
+export type Status = string;
+
+export type IDs = number[];
+
+export type Lookup = { [key: string]: ?Region };
+
+export type Grid = number[][];
+
+export type Region = {
+  Name: string,
+  Neighbors: Array<?Region>,
+  Index: { [key: number]: string },
+  Matrix: number[][],
+  Avatar: string,
+  Settings: {
+    theme: string,
+    Limits: {
+      Daily: number,
+    },
+  },
+  Items: Array<{
+    id: string,
+  }>,
+};
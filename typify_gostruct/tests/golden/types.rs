@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+pub type Status = String;
+
+pub type IDs = Vec<i64>;
+
+pub type Lookup = HashMap<String, Option<Region>>;
+
+pub type Grid = [[i64; 3]; 3];
+
+#[derive(Serialize, Deserialize)]
+pub struct Region {
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Neighbors")]
+    pub neighbors: Vec<Option<Box<Region>>>,
+    #[serde(rename = "Index")]
+    pub index: HashMap<i64, String>,
+    #[serde(rename = "Matrix")]
+    pub matrix: Vec<Vec<f64>>,
+    #[serde(rename = "Avatar")]
+    pub avatar: String,
+    #[serde(rename = "Settings")]
+    pub settings: RegionSettings,
+    #[serde(rename = "Items")]
+    pub items: Vec<RegionItems>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RegionSettings {
+    pub theme: String,
+    #[serde(rename = "Limits")]
+    pub limits: RegionSettingsLimits,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RegionItems {
+    pub id: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RegionSettingsLimits {
+    #[serde(rename = "Daily")]
+    pub daily: i64,
+}
@@ -0,0 +1,24 @@
+export type Status = string;
+
+export type IDs = number[];
+
+export type Lookup = Record<string, Region | null>;
+
+export type Grid = number[][];
+
+export interface Region {
+  Name: string;
+  Neighbors: Array<Region | null>;
+  Index: Record<number, string>;
+  Matrix: number[][];
+  Avatar: string;
+  Settings: {
+    theme: string;
+    Limits: {
+      Daily: number;
+    };
+  };
+  Items: Array<{
+    id: string;
+  }>;
+}
@@ -0,0 +1,48 @@
+import { z } from "zod";
+
+export const StatusSchema = z.string();
+export type Status = z.infer<typeof StatusSchema>;
+
+export const IDsSchema = z.array(z.number().int());
+export type IDs = z.infer<typeof IDsSchema>;
+
+export type Region = {
+  Name: string;
+  Neighbors: Array<Region | null>;
+  Index: Record<number, string>;
+  Matrix: number[][];
+  Avatar: string;
+  Settings: {
+    theme: string;
+    Limits: {
+      Daily: number;
+    };
+  };
+  Items: Array<{
+    id: string;
+  }>;
+};
+export const RegionSchema: z.ZodType<Region> = z.lazy(() =>
+  z.object({
+    Name: z.string(),
+    Neighbors: z.array(RegionSchema.nullable()),
+    Index: z.record(z.string(), z.string()),
+    Matrix: z.array(z.array(z.number())),
+    Avatar: z.string(),
+    Settings: z.object({
+      theme: z.string(),
+      Limits: z.object({
+        Daily: z.number().int(),
+      }),
+    }),
+    Items: z.array(z.object({
+      id: z.string(),
+    })),
+  }),
+);
+
+export const LookupSchema = z.record(z.string(), RegionSchema.nullable());
+export type Lookup = z.infer<typeof LookupSchema>;
+
+export const GridSchema = z.array(z.array(z.number().int()).length(3)).length(3);
+export type Grid = z.infer<typeof GridSchema>;
@@ -1,6 +1,9 @@
 mod utils;
 
-use typify_gostruct::Source;
+use typify_gostruct::{
+    DeclarationStyle, EnumStyle, ExportStyle, Indent, LargeIntegerStyle, ObjectStyle, Options,
+    QuoteStyle, Separator, Source, TypeMapping,
+};
 use wasm_bindgen::prelude::*;
 
 // When the `wee_alloc` feature is enabled, use `wee_alloc` as the global
@@ -14,14 +17,262 @@ static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
 ///
 /// * `source` - A string representation of the go struct to be transfomed.
 ///
-/// * `to` - To represents the target interpreter that will be used for the transformation. Can either be "flow" | "typescript"
+/// * `to` - To represents the target interpreter that will be used for the transformation. Can either be "flow" | "typescript" | "jsonschema" | "zod" | "io-ts" | "rust"
 ///
 ///
-/// * #### N/B: The targets that can be used as of now are, "flow" & "typescript" (more to come...).
+/// * #### N/B: The targets that can be used as of now are, "flow", "typescript", "jsonschema", "zod", "io-ts" & "rust" (more to come...).
 #[wasm_bindgen]
 pub fn transform(source: &str, to: &str) -> std::result::Result<String, JsValue> {
     let source = Source::new(source);
     source
         .transform_to(to)
-        .map_err(|err| JsValue::from_str(&err.join("")))
+        .map_err(|err| JsValue::from_str(&err.join("\n")))
+}
+
+/// The outcome of `transform_partially`.
+#[wasm_bindgen]
+pub struct PartialTransform {
+    output: String,
+    errors: Vec<String>,
+}
+
+#[wasm_bindgen]
+impl PartialTransform {
+    /// The transformed declarations that parsed cleanly.
+    #[wasm_bindgen(getter)]
+    pub fn output(&self) -> String {
+        self.output.clone()
+    }
+
+    /// Every parse error found in the source.
+    #[wasm_bindgen(getter)]
+    pub fn errors(&self) -> Vec<String> {
+        self.errors.clone()
+    }
+}
+
+/// Returns the transformation of every declaration that parsed cleanly, along with the errors
+/// found in the rest of the source instead of failing on the first one.
+#[wasm_bindgen]
+pub fn transform_partially(source: &str, to: &str) -> Result<PartialTransform, JsValue> {
+    let source = Source::new(source);
+    let typify_gostruct::PartialTransform { output, errors } = source
+        .transform_partially_to(to)
+        .map_err(|err| JsValue::from_str(&err.join("\n")))?;
+    Ok(PartialTransform { output, errors })
+}
+
+/// Options that tweak the output of `transform_with_options`.
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct TransformOptions {
+    options: Options,
+}
+
+#[wasm_bindgen]
+impl TransformOptions {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps a go-lang type name (qualified or not) to a type expression of the target language.
+    /// # Arguments
+    ///
+    /// * `go_type` - The go-lang type name, eg `money.Amount` or `TenantID`.
+    ///
+    /// * `type_expression` - What the type becomes in the output.
+    ///
+    /// * `import` - An optional import statement emitted at the top of the output when the type is used.
+    pub fn map_type(&mut self, go_type: &str, type_expression: &str, import: Option<String>) {
+        let mut mapping = TypeMapping::new(type_expression);
+        mapping.import = import;
+        self.options
+            .type_mappings
+            .insert(go_type.to_string(), mapping);
+    }
+
+    /// Whether pointer fields are also optional properties, on top of being nullable.
+    #[wasm_bindgen(setter)]
+    pub fn set_optional_pointers(&mut self, optional_pointers: bool) {
+        self.options.optional_pointers = optional_pointers;
+    }
+
+    /// Whether unexported (lower case) fields are kept even though encoding/json ignores them.
+    #[wasm_bindgen(setter)]
+    pub fn set_keep_unexported_fields(&mut self, keep_unexported_fields: bool) {
+        self.options.keep_unexported_fields = keep_unexported_fields;
+    }
+
+    /// How `int64` & `uint64` are emitted, either "number" | "string" | "bigint".
+    #[wasm_bindgen(setter)]
+    pub fn set_large_integers(&mut self, large_integers: &str) -> Result<(), JsValue> {
+        self.options.large_integers = match large_integers {
+            "number" => LargeIntegerStyle::Number,
+            "string" => LargeIntegerStyle::String,
+            "bigint" => LargeIntegerStyle::BigInt,
+            _ => return Err(unexpected_option("large_integers", large_integers)),
+        };
+        Ok(())
+    }
+
+    /// How typescript structs are declared, either "interface" | "type" | "declare".
+    #[wasm_bindgen(setter)]
+    pub fn set_typescript_declarations(&mut self, declarations: &str) -> Result<(), JsValue> {
+        self.options.typescript.declarations = match declarations {
+            "interface" => DeclarationStyle::Interface,
+            "type" => DeclarationStyle::TypeAlias,
+            "declare" => DeclarationStyle::Declare,
+            _ => return Err(unexpected_option("declarations", declarations)),
+        };
+        Ok(())
+    }
+
+    /// How typescript declarations are exported, either "named" | "default" | "none".
+    #[wasm_bindgen(setter)]
+    pub fn set_typescript_exports(&mut self, exports: &str) -> Result<(), JsValue> {
+        self.options.typescript.exports = match exports {
+            "named" => ExportStyle::Named,
+            "default" => ExportStyle::Default,
+            "none" => ExportStyle::None,
+            _ => return Err(unexpected_option("exports", exports)),
+        };
+        Ok(())
+    }
+
+    /// Whether every typescript property is `readonly`.
+    #[wasm_bindgen(setter)]
+    pub fn set_typescript_readonly(&mut self, readonly: bool) {
+        self.options.typescript.readonly = readonly;
+    }
+
+    /// How typescript types with `const` values are emitted, either "union" | "enum".
+    #[wasm_bindgen(setter)]
+    pub fn set_typescript_enums(&mut self, enums: &str) -> Result<(), JsValue> {
+        self.options.typescript.enums = match enums {
+            "union" => EnumStyle::Union,
+            "enum" => EnumStyle::Enum,
+            _ => return Err(unexpected_option("enums", enums)),
+        };
+        Ok(())
+    }
+
+    /// How flow object types are delimited, either "implicit" | "exact".
+    #[wasm_bindgen(setter)]
+    pub fn set_flow_objects(&mut self, objects: &str) -> Result<(), JsValue> {
+        self.options.flow.objects = match objects {
+            "implicit" => ObjectStyle::Implicit,
+            "exact" => ObjectStyle::Exact,
+            _ => return Err(unexpected_option("objects", objects)),
+        };
+        Ok(())
+    }
+
+    /// Whether every flow property is covariant (`+prop`).
+    #[wasm_bindgen(setter)]
+    pub fn set_flow_readonly(&mut self, readonly: bool) {
+        self.options.flow.readonly = readonly;
+    }
+
+    /// Whether flow slices & arrays are `$ReadOnlyArray<T>`.
+    #[wasm_bindgen(setter)]
+    pub fn set_flow_readonly_arrays(&mut self, readonly_arrays: bool) {
+        self.options.flow.readonly_arrays = readonly_arrays;
+    }
+
+    /// The indentation of the output, either "tabs" or a number of spaces.
+    #[wasm_bindgen(setter)]
+    pub fn set_indent(&mut self, indent: &str) -> Result<(), JsValue> {
+        self.options.formatting.indent = match indent {
+            "tabs" => Indent::Tabs,
+            spaces => match spaces.parse() {
+                Ok(width) => Indent::Spaces(width),
+                Err(_) => return Err(unexpected_option("indent", indent)),
+            },
+        };
+        Ok(())
+    }
+
+    /// What separates the members of object types, either "semicolon" | "comma".
+    #[wasm_bindgen(setter)]
+    pub fn set_separator(&mut self, separator: &str) -> Result<(), JsValue> {
+        self.options.formatting.separator = match separator {
+            "semicolon" => Some(Separator::Semicolon),
+            "comma" => Some(Separator::Comma),
+            _ => return Err(unexpected_option("separator", separator)),
+        };
+        Ok(())
+    }
+
+    /// The quotes of string literals & property names, either "double" | "single".
+    #[wasm_bindgen(setter)]
+    pub fn set_quotes(&mut self, quotes: &str) -> Result<(), JsValue> {
+        self.options.formatting.quotes = match quotes {
+            "double" => QuoteStyle::Double,
+            "single" => QuoteStyle::Single,
+            _ => return Err(unexpected_option("quotes", quotes)),
+        };
+        Ok(())
+    }
+
+    /// Whether the output ends with a newline.
+    #[wasm_bindgen(setter)]
+    pub fn set_trailing_newline(&mut self, trailing_newline: bool) {
+        self.options.formatting.trailing_newline = trailing_newline;
+    }
+}
+
+fn unexpected_option(option: &str, value: &str) -> JsValue {
+    JsValue::from_str(&format!("`{}` isn't a valid value for {}", value, option))
+}
+
+/// Returns the transformed go-lang struct, same as `transform` but with the provided options.
+#[wasm_bindgen]
+pub fn transform_with_options(
+    source: &str,
+    to: &str,
+    options: &TransformOptions,
+) -> std::result::Result<String, JsValue> {
+    let source = Source::new(source).with_options(options.options.clone());
+    source
+        .transform_to(to)
+        .map_err(|err| JsValue::from_str(&err.join("\n")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{transform_with_options, TransformOptions};
+
+    #[test]
+    fn should_emit_typescript_enums_when_asked() {
+        let source = r#"
+        type Role string
+        const (
+            RoleAdmin Role = "admin"
+        )
+    "#;
+        let mut options = TransformOptions::new();
+        options
+            .set_typescript_enums("enum")
+            .expect("enum is a valid style");
+        let result = transform_with_options(source, "typescript", &options)
+            .expect("The types should be transformed without an issue");
+        assert!(result.contains("export enum Role {\n  RoleAdmin = \"admin\",\n}"));
+    }
+
+    #[test]
+    fn should_emit_large_integers_as_asked() {
+        let source = r#"
+        type Account struct {
+            Balance int64
+        }
+    "#;
+        let mut options = TransformOptions::new();
+        options
+            .set_large_integers("string")
+            .expect("string is a valid style");
+        let result = transform_with_options(source, "typescript", &options)
+            .expect("The struct should be transformed without an issue");
+        assert!(result.contains("Balance: string"));
+    }
 }